@@ -0,0 +1,134 @@
+use std::{
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{open_input, AocError, Day};
+
+/// How many times each stage of a day is run
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// untimed runs before measuring
+    pub warmup: usize,
+    /// timed runs
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: 10,
+        }
+    }
+}
+
+/// Min, median and max over the timed runs of one stage
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of one day, with each stage measured separately
+#[derive(Debug, Clone, Copy)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayTimings {
+    pub fn stages(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+fn measure<T>(config: BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples = (0..config.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(samples)
+}
+
+/// times parsing (including reading the file) and each part of `day` on the
+/// input at `path`
+pub fn bench(day: &Day, path: &Path, config: BenchConfig) -> Result<DayTimings, AocError> {
+    // fail on a bad input before timing anything
+    let solution = day.parse(open_input(path)?)?;
+    solution.part1().map_err(|e| e.in_file(path))?;
+    solution.part2().map_err(|e| e.in_file(path))?;
+
+    let parse = measure(config, || {
+        open_input(path).and_then(|input| day.parse(input))
+    });
+    let part1 = measure(config, || solution.part1().map(|a| a.to_string()));
+    let part2 = measure(config, || solution.part2().map(|a| a.to_string()));
+
+    Ok(DayTimings {
+        day: day.number,
+        parse,
+        part1,
+        part2,
+    })
+}
+
+pub fn print_table(timings: &[DayTimings]) {
+    println!(
+        "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "day", "stage", "min", "median", "max"
+    );
+    for t in timings {
+        for (stage, stats) in t.stages() {
+            println!(
+                "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                t.day,
+                stage,
+                format!("{:.3?}", stats.min),
+                format!("{:.3?}", stats.median),
+                format!("{:.3?}", stats.max)
+            );
+        }
+    }
+}
+
+pub fn print_csv(timings: &[DayTimings]) {
+    println!("day,stage,min_ns,median_ns,max_ns");
+    for t in timings {
+        for (stage, stats) in t.stages() {
+            println!(
+                "{},{},{},{},{}",
+                t.day,
+                stage,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            );
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{parse_number, Answer, AocError, Day, InputFileBuffer, Solution};
+
+pub const DAY: Day = Day::new::<Reports>(2);
+
+fn parse_numbers(nums: &str, line: usize) -> Result<Vec<i32>, AocError> {
+    let numbers = nums
+        .split_whitespace()
+        .map(|s| parse_number::<i32>(s, line))
+        .collect::<Result<Vec<i32>, AocError>>()?;
+    if numbers.is_empty() {
+        return Err(AocError::parse(line, "empty report"));
+    }
+    Ok(numbers)
+}
+
+fn is_increasing(nums: &[i32]) -> bool {
+    let mut prior = &nums[0];
+    for current in &nums[1..] {
+        if !(prior < current && (prior - current).abs() <= 3) {
+            return false;
+        }
+        prior = current;
+    }
+    true
+}
+
+fn is_decreasing(nums: &[i32]) -> bool {
+    let mut prior = &nums[0];
+    for current in &nums[1..] {
+        if !(prior > current && (prior - current).abs() <= 3) {
+            return false;
+        }
+        prior = current;
+    }
+    true
+}
+
+fn safe(nums: &[i32]) -> bool {
+    is_increasing(nums) || is_decreasing(nums)
+}
+
+fn dampened(nums: &[i32]) -> bool {
+    if safe(nums) {
+        return true;
+    }
+    for (i, _) in nums.iter().enumerate() {
+        let num_subset = [&nums[..i], &nums[i + 1..]].concat();
+        if safe(&num_subset) {
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Reports {
+    numbers: Vec<Vec<i32>>,
+}
+
+impl Solution for Reports {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        let numbers = input
+            .numbered()
+            .map(|l| l.and_then(|(i, l)| parse_numbers(&l, i)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { numbers })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.numbers.iter().filter(|nums| safe(nums)).count(),
+        ))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.numbers.iter().filter(|nums| dampened(nums)).count(),
+        ))
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{parse_number, Answer, AocError, Day, InputFileBuffer, Solution};
+
+use regex::{Captures, Regex};
+
+pub const DAY: Day = Day::new::<Memory>(3);
+
+enum Instruction {
+    Mul((i32, i32)),
+    Do,
+    DoNot,
+}
+
+// operands have 1 to 3 digits, so anything longer is corrupted memory
+const PATTERN: &str = r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)";
+
+fn parse_instruction(capture: Captures, line: usize) -> Result<Instruction, AocError> {
+    match (capture.get(1), capture.get(2)) {
+        (Some(x), Some(y)) => Ok(Instruction::Mul((
+            parse_number(x.as_str(), line)?,
+            parse_number(y.as_str(), line)?,
+        ))),
+        _ if &capture[0] == "do()" => Ok(Instruction::Do),
+        _ => Ok(Instruction::DoNot),
+    }
+}
+
+fn sum_product<'a>(operands: impl Iterator<Item = &'a (i32, i32)>) -> i32 {
+    operands.map(|op| op.0 * op.1).sum()
+}
+
+pub struct Memory {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Memory {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        let re = Regex::new(PATTERN).unwrap();
+        let mut instructions = vec![];
+        for line in input.numbered() {
+            let (i, line) = line?;
+            for capture in re.captures_iter(&line) {
+                instructions.push(parse_instruction(capture, i)?);
+            }
+        }
+        Ok(Self { instructions })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mul = self.instructions.iter().filter_map(|i| match i {
+            Instruction::Mul(op) => Some(op),
+            _ => None,
+        });
+        Ok(Box::new(sum_product(mul)))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut enabled = true;
+        let mut mul: Vec<&(i32, i32)> = vec![];
+        for instruction in &self.instructions {
+            match instruction {
+                Instruction::Mul(op) => {
+                    if enabled {
+                        mul.push(op);
+                    }
+                }
+                Instruction::Do => {
+                    enabled = true;
+                }
+                Instruction::DoNot => {
+                    enabled = false;
+                }
+            }
+        }
+        Ok(Box::new(sum_product(mul.into_iter())))
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Answer, AocError, Day, Direction8, FromChar, Grid, InputFileBuffer, Point, Solution};
+
+pub const DAY: Day = Day::new::<Crossword>(4);
+
+const S_M: &[XMAS] = &[XMAS::S, XMAS::M];
+
+#[derive(Debug, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+enum XMAS {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl XMAS {
+    fn next(&self) -> Option<XMAS> {
+        match self {
+            XMAS::X => Some(XMAS::M),
+            XMAS::M => Some(XMAS::A),
+            XMAS::A => Some(XMAS::S),
+            XMAS::S => None,
+        }
+    }
+}
+
+impl FromChar for XMAS {
+    fn from_char(s: char) -> Option<XMAS> {
+        match s {
+            'X' => Some(XMAS::X),
+            'M' => Some(XMAS::M),
+            'A' => Some(XMAS::A),
+            'S' => Some(XMAS::S),
+            _ => None,
+        }
+    }
+}
+
+fn check_direction(board: &Grid<XMAS>, start: Point, direction: Direction8) -> bool {
+    let mut symbol = XMAS::X;
+    let mut position = start;
+
+    while let Some(next_symbol) = symbol.next() {
+        position += direction;
+
+        if board.get(position) != Some(&next_symbol) {
+            return false;
+        }
+        symbol = next_symbol;
+    }
+
+    true
+}
+
+// checks that the ends of the diagonal through `a` along `d` are an S and an M
+fn is_cross(board: &Grid<XMAS>, a: Point, d: Direction8) -> bool {
+    let (Some(b_symbol), Some(c_symbol)) = (board.get(a + d), board.get(a + d.opposite())) else {
+        return false;
+    };
+
+    b_symbol != c_symbol && S_M.contains(b_symbol) && S_M.contains(c_symbol)
+}
+
+fn is_x_mas(board: &Grid<XMAS>, a: Point) -> bool {
+    is_cross(board, a, Direction8::UpLeft) && is_cross(board, a, Direction8::UpRight)
+}
+
+fn matches(board: &Grid<XMAS>, x: Point) -> i32 {
+    Direction8::iter()
+        .map(|d| check_direction(board, x, d) as i32)
+        .sum()
+}
+
+pub struct Crossword {
+    crossword: Grid<XMAS>,
+}
+
+impl Solution for Crossword {
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        Ok(Self {
+            crossword: Grid::parse(&mut input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.crossword
+                .positions(|s| *s == XMAS::X)
+                .map(|x| matches(&self.crossword, x))
+                .sum::<i32>(),
+        ))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.crossword
+                .positions(|s| *s == XMAS::A)
+                .filter(|a| is_x_mas(&self.crossword, *a))
+                .count(),
+        ))
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+use crate::{parse_number, Answer, AocError, Day, InputFileBuffer, Solution};
+
+pub const DAY: Day = Day::new::<PrintQueue>(5);
+
+type Follower = HashMap<i32, HashSet<i32>>;
+
+type Leader = HashMap<i32, HashSet<i32>>;
+
+fn parse_record(l: &str, line: usize) -> Result<Vec<i32>, AocError> {
+    l.split(",").map(|s| parse_number::<i32>(s, line)).collect()
+}
+
+fn add_follower(
+    followers: &mut Follower,
+    leaders: &mut Leader,
+    s: &str,
+    line: usize,
+) -> Result<(), AocError> {
+    if let Some((first_str, follower_str)) = s.split_once("|") {
+        let first = parse_number::<i32>(first_str, line)?;
+        let follower = parse_number::<i32>(follower_str, line)?;
+
+        if let Some(entry) = followers.get_mut(&first) {
+            entry.insert(follower);
+        } else {
+            let mut set = HashSet::new();
+            set.insert(follower);
+
+            followers.insert(first, set);
+        }
+
+        if let Some(entry) = leaders.get_mut(&follower) {
+            entry.insert(first);
+        } else {
+            let mut set = HashSet::new();
+            set.insert(first);
+
+            leaders.insert(follower, set);
+        }
+        Ok(())
+    } else {
+        Err(AocError::expected(line, "X|Y"))
+    }
+}
+
+fn valid_record(followers: &Follower, leaders: &Leader, record: &[i32]) -> bool {
+    let mut preceding_set = HashSet::new();
+    let mut following_set: HashSet<i32> = HashSet::from_iter(record.iter().copied());
+
+    // a record is valid if for every number, none of the things that should
+    // follow appear ahead, and none of the things that should appear ahead, follow
+    for num in record {
+        following_set.remove(num);
+        if let Some(follower) = followers.get(num) {
+            if follower.intersection(&preceding_set).count() > 0 {
+                return false;
+            }
+        }
+        if let Some(leader) = leaders.get(num) {
+            if leader.intersection(&following_set).count() > 0 {
+                return false;
+            }
+        }
+        preceding_set.insert(*num);
+    }
+    true
+}
+
+/// returns true if a should be after b
+fn after(followers: &Follower, a: i32, b: i32) -> bool {
+    // a should be after be if a is in b's followers set or if b is in a's leader
+    if let Some(follower) = followers.get(&b) {
+        if follower.contains(&a) {
+            return true;
+        }
+    }
+    // a should not be after b if b is in a's follower set
+    // default to no swap
+    false
+}
+
+fn bubble_sort(followers: &Follower, record: &[i32]) -> i32 {
+    let mut sorted_record = record.to_vec();
+    loop {
+        let mut swapped = false;
+        for i in 0..(sorted_record.len() - 1) {
+            if after(followers, sorted_record[i], sorted_record[i + 1]) {
+                sorted_record.swap(i, i + 1);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+    }
+    sorted_record[sorted_record.len() / 2]
+}
+
+pub struct PrintQueue {
+    followers: Follower,
+    leaders: Leader,
+    records: Vec<Vec<i32>>,
+}
+
+impl Solution for PrintQueue {
+    fn parse(mut lines: InputFileBuffer) -> Result<Self, AocError> {
+        let mut followers: Follower = HashMap::new();
+        let mut leaders: Leader = HashMap::new();
+
+        while let Some(res) = lines.next() {
+            let s = res?;
+            if s.is_empty() {
+                break;
+            }
+            add_follower(&mut followers, &mut leaders, &s, lines.line())?;
+        }
+
+        let records: Vec<Vec<i32>> = lines
+            .numbered()
+            .map(|l| l.and_then(|(i, l)| parse_record(&l, i)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            followers,
+            leaders,
+            records,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.records
+                .iter()
+                .filter(|r| valid_record(&self.followers, &self.leaders, r))
+                .map(|r| r[r.len() / 2])
+                .sum::<i32>(),
+        ))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.records
+                .iter()
+                .filter(|r| !valid_record(&self.followers, &self.leaders, r))
+                .map(|r| bubble_sort(&self.followers, r))
+                .sum::<i32>(),
+        ))
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Answer, AocError, Day, Direction, FromChar, Grid, InputFileBuffer, Point, Solution};
+
+pub const DAY: Day = Day::new::<Lab>(6);
+
+struct History {
+    history: HashMap<Point, HashSet<Direction>>,
+}
+
+impl History {
+    fn new() -> Self {
+        History {
+            history: HashMap::new(),
+        }
+    }
+
+    fn add_history(&mut self, position: &Point, direction: Direction) {
+        if let Some(collection) = self.history.get_mut(position) {
+            collection.insert(direction);
+        } else {
+            let mut set = HashSet::new();
+            set.insert(direction);
+            self.history.insert(*position, set);
+        }
+    }
+
+    fn check_history(&self, position: &Point, direction: &Direction) -> bool {
+        if let Some(entry) = self.history.get(position) {
+            return entry.contains(direction);
+        }
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TileType {
+    Void,
+    Block,
+    Guard,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tile {
+    visited: bool,
+    tile_type: TileType,
+}
+
+impl FromChar for Tile {
+    fn from_char(s: char) -> Option<Self> {
+        let tile_type = match s {
+            '.' => TileType::Void,
+            '^' => TileType::Guard,
+            '#' => TileType::Block,
+            _ => return None,
+        };
+        Some(Tile {
+            visited: false,
+            tile_type,
+        })
+    }
+}
+
+fn next(tiles: &Grid<Tile>, position: Point, direction: Direction) -> Option<(Point, Direction)> {
+    let next_position = position + direction;
+
+    match tiles.get(next_position)?.tile_type {
+        TileType::Void | TileType::Guard => Some((next_position, direction)),
+        TileType::Block => Some((position, direction.turn_clockwise())),
+    }
+}
+
+fn visit(tiles: &mut Grid<Tile>, start: Point) {
+    tiles[start].visited = true;
+    let mut position = start;
+    let mut direction = Direction::Up;
+    while let Some((next_position, next_direction)) = next(tiles, position, direction) {
+        direction = next_direction;
+        position = next_position;
+        tiles[position].visited = true;
+    }
+}
+
+fn contains_loop(tiles: &Grid<Tile>, start: Point) -> bool {
+    let mut position = start;
+    let mut direction = Direction::Up;
+    let mut history = History::new();
+
+    while let Some((next_position, next_direction)) = next(tiles, position, direction) {
+        direction = next_direction;
+        position = next_position;
+        if history.check_history(&position, &direction) {
+            return true;
+        }
+        history.add_history(&position, direction);
+    }
+
+    false
+}
+
+fn loops(tiles: &Grid<Tile>, start: Point) -> i32 {
+    let mut counter = 0;
+    for p in tiles.positions(|t| t.visited) {
+        let mut board = tiles.clone();
+        board[p].tile_type = TileType::Block;
+        if contains_loop(&board, start) {
+            counter += 1;
+        }
+    }
+    counter
+}
+
+pub struct Lab {
+    tiles: Grid<Tile>,
+    guard: Point,
+}
+
+impl Solution for Lab {
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        let mut tiles: Grid<Tile> = Grid::parse(&mut input)?;
+
+        let guards: Vec<Point> = tiles
+            .positions(|t| t.tile_type == TileType::Guard)
+            .collect();
+        let Some(&guard) = guards.first() else {
+            return Err(AocError::expected(input.line() + 1, "^"));
+        };
+        if let Some(other) = guards.get(1) {
+            return Err(AocError::parse(
+                other.i as usize + 1,
+                "found more than one guard",
+            ));
+        }
+
+        visit(&mut tiles, guard);
+
+        Ok(Self { tiles, guard })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(
+            self.tiles.iter().filter(|(_, t)| t.visited).count(),
+        ))
+    }
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(loops(&self.tiles, self.guard)))
+    }
+}
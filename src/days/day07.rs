@@ -0,0 +1,120 @@
+use crate::{parse_number, Answer, AocError, Day, InputFileBuffer, Solution};
+
+pub const DAY: Day = Day::new::<Calibrations>(7);
+
+struct Calibration {
+    value: u64,
+    operands: Vec<u64>,
+}
+
+impl Calibration {
+    fn from(s: &str, line: usize) -> Result<Self, AocError> {
+        if let Some(split) = s.split_once(":") {
+            let value = parse_number::<u64>(split.0, line)?;
+            let operands: Vec<u64> = split
+                .1
+                .split_whitespace()
+                .map(|s| parse_number::<u64>(s, line))
+                .collect::<Result<_, _>>()?;
+            if operands.is_empty() {
+                return Err(AocError::parse(line, "calibration has no operands"));
+            }
+            Ok(Calibration { value, operands })
+        } else {
+            Err(AocError::expected(line, ":"))
+        }
+    }
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+fn mult(a: u64, b: u64) -> u64 {
+    a * b
+}
+
+fn concat(a: u64, b: u64) -> u64 {
+    let order;
+    if b < 10 {
+        order = 1;
+    } else if b < 100 {
+        order = 2;
+    } else {
+        order = 3;
+    }
+
+    a * 10_u64.pow(order) + b
+}
+
+fn is_valid(calibration: &Calibration, functions: &[fn(u64, u64) -> u64]) -> bool {
+    let target = calibration.value;
+    let mut stack = vec![(calibration.operands[0], &calibration.operands[1..])];
+    while let Some(next) = stack.pop() {
+        if next.1.is_empty() {
+            if next.0 == target {
+                return true;
+            } else {
+                continue;
+            }
+        }
+
+        if next.0 > target {
+            continue;
+        }
+
+        stack.extend(
+            functions
+                .iter()
+                .map(|f| (f(next.0, next.1[0]), &next.1[1..])),
+        )
+    }
+
+    false
+}
+
+fn total_calibrations(calibrations: &[Calibration], methods: &[fn(u64, u64) -> u64]) -> u64 {
+    let validation = |cal| is_valid(cal, methods);
+    calibrations
+        .iter()
+        .filter_map(|cal| {
+            if validation(cal) {
+                Some(cal.value)
+            } else {
+                None
+            }
+        })
+        .sum::<u64>()
+}
+
+pub struct Calibrations {
+    calibrations: Vec<Calibration>,
+}
+
+impl Solution for Calibrations {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        let calibrations = input
+            .numbered()
+            .map(|l| l.and_then(|(i, l)| Calibration::from(&l, i)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { calibrations })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let pt1_methods: Vec<fn(u64, u64) -> u64> = vec![add, mult];
+
+        Ok(Box::new(total_calibrations(
+            &self.calibrations,
+            &pt1_methods,
+        )))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let pt2_methods: Vec<fn(u64, u64) -> u64> = vec![add, mult, concat];
+
+        Ok(Box::new(total_calibrations(
+            &self.calibrations,
+            &pt2_methods,
+        )))
+    }
+}
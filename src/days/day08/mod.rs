@@ -0,0 +1,130 @@
+use gcd::gcd;
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, HashSet},
+    hash::RandomState,
+};
+
+use crate::{Answer, AocError, Day, Grid, InputFileBuffer, Point, Solution};
+
+mod gcd;
+
+pub const DAY: Day = Day::new::<Antennas>(8);
+
+pub struct Antennas {
+    antennas: HashMap<char, Vec<Point>>,
+    map: Grid<char>,
+}
+
+impl Antennas {
+    fn new(map: Grid<char>) -> Self {
+        Self {
+            antennas: HashMap::new(),
+            map,
+        }
+    }
+
+    fn add_point(&mut self, c: char, point: Point) {
+        if let Some(entry) = self.antennas.get_mut(&c) {
+            entry.push(point);
+        } else {
+            let vec = vec![point];
+            self.antennas.insert(c, vec);
+        }
+    }
+}
+
+fn point_antinodes(a: &Point, b: &Point) -> Vec<Point> {
+    let offset = *a - *b;
+
+    vec![*a + offset, *b - offset]
+}
+
+fn antinodes(points: &[Point]) -> Vec<Point> {
+    points
+        .iter()
+        .combinations(2)
+        .flat_map(|v| point_antinodes(v[0], v[1]))
+        .collect()
+}
+
+fn reduce(p: Point) -> Point {
+    let factor = gcd(p.i.unsigned_abs(), p.j.unsigned_abs()) as i32;
+    Point::new(p.i / factor, p.j / factor)
+}
+
+fn extended_point_entinodes(map: &Grid<char>, a: &Point, b: &Point) -> Vec<Point> {
+    let step = reduce(*a - *b);
+
+    let mut points = vec![];
+    let mut p = *a;
+    loop {
+        p += step;
+        if !map.in_bounds(p) {
+            break;
+        }
+        points.push(p);
+    }
+    p = *a;
+    loop {
+        p -= step;
+        if !map.in_bounds(p) {
+            break;
+        }
+        points.push(p);
+    }
+
+    points
+}
+
+fn extended_antinodes(map: &Grid<char>, points: &[Point]) -> Vec<Point> {
+    let antinodes = points
+        .iter()
+        .combinations(2)
+        .flat_map(|v| extended_point_entinodes(map, v[0], v[1]));
+
+    if points.len() > 2 {
+        return points.iter().copied().chain(antinodes).collect();
+    }
+    antinodes.collect()
+}
+
+impl Solution for Antennas {
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        let map: Grid<char> = Grid::parse(&mut input)?;
+        let mut antennas = Antennas::new(map.clone());
+
+        for (p, c) in map.iter() {
+            if *c != '.' {
+                antennas.add_point(*c, p);
+            }
+        }
+
+        Ok(antennas)
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let all_antinodes = self
+            .antennas
+            .values()
+            .flat_map(|v| antinodes(v))
+            .filter(|p| self.map.in_bounds(*p));
+
+        let unique: HashSet<Point, RandomState> = HashSet::from_iter(all_antinodes);
+
+        Ok(Box::new(unique.len()))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let all_extended_antinodes = self
+            .antennas
+            .values()
+            .flat_map(|v| extended_antinodes(&self.map, v))
+            .filter(|p| self.map.in_bounds(*p));
+
+        let extended_unique: HashSet<Point, RandomState> =
+            HashSet::from_iter(all_extended_antinodes);
+
+        Ok(Box::new(extended_unique.len()))
+    }
+}
@@ -0,0 +1,579 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap},
+    fmt::{self, Display, Formatter},
+    path::Path,
+};
+
+use crate::{open_input, parse_chars, Answer, AocError, Day, InputFileBuffer, Mode, Solution};
+
+pub const DAY: Day = Day::new::<FileSystem>(9).with_modes(&[Mode {
+    name: "compact",
+    usage: "<input> [--strategy NAME] [--replay]",
+    run: compact,
+}]);
+
+// the most blocks a disk may have to be replayed move by move
+const REPLAY_LIMIT: usize = 200;
+
+/// A run of blocks on the disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Span {
+    start: usize,
+    size: usize,
+}
+
+pub struct FileSystem {
+    blocks: Vec<Option<u64>>,
+    /// where each file starts out, indexed by id
+    files: Vec<Span>,
+    /// the free space between the files, from left to right
+    free: Vec<Span>,
+}
+
+impl FileSystem {
+    fn from(storage: Vec<u64>) -> Self {
+        let mut blocks = vec![];
+        let mut files = vec![];
+        let mut free = vec![];
+
+        for (k, &size) in storage.iter().enumerate() {
+            let span = Span {
+                start: blocks.len(),
+                size: size as usize,
+            };
+            // the map alternates between a file and the free space after it
+            if k % 2 == 0 {
+                blocks.extend(std::iter::repeat_n(Some(files.len() as u64), span.size));
+                files.push(span);
+            } else {
+                blocks.extend(std::iter::repeat_n(None, span.size));
+                free.push(span);
+            }
+        }
+
+        Self {
+            blocks,
+            files,
+            free,
+        }
+    }
+}
+
+/// A file, or one block of it, moving to free space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Move {
+    id: u64,
+    from: usize,
+    to: usize,
+    size: usize,
+}
+
+impl Move {
+    /// moves the blocks, which may overlap where they came from
+    fn apply(&self, blocks: &mut [Option<u64>]) {
+        blocks[self.from..self.from + self.size].fill(None);
+        blocks[self.to..self.to + self.size].fill(Some(self.id));
+    }
+}
+
+/// moves one block at a time from the end of the disk into the leftmost free
+/// block, until there are no gaps
+fn compact_blocks(blocks: &[Option<u64>]) -> Vec<Move> {
+    let mut moves = vec![];
+    let (mut left, mut right) = (0, blocks.len());
+    loop {
+        while left < right && blocks[left].is_some() {
+            left += 1;
+        }
+        while right > left && blocks[right - 1].is_none() {
+            right -= 1;
+        }
+        if left + 1 >= right {
+            break;
+        }
+        right -= 1;
+        moves.push(Move {
+            id: blocks[right].expect("the right pointer stops on a file"),
+            from: right,
+            to: left,
+            size: 1,
+        });
+        left += 1;
+    }
+    moves
+}
+
+/// Which of the free spans before a file that can hold it the file moves to
+#[derive(Debug, Clone, Copy)]
+enum Fit {
+    Leftmost,
+    /// the smallest, then the leftmost
+    Best,
+    /// the largest, then the leftmost
+    Worst,
+}
+
+/// moves each file once, from the highest id down, to a free span before it
+/// that can hold it, chosen by `fit`.
+///
+/// The starts of the free spans are kept in a min-heap per span size, so the
+/// leftmost span of each size is at the top of its heap, and the span chosen
+/// is among the heap tops for the sizes at least as large as the file. Space
+/// a file leaves behind is never reused, as every file still to move lies to
+/// its left
+fn compact_files(files: &[Span], free: &[Span], fit: Fit) -> Vec<Move> {
+    let largest = free.iter().map(|s| s.size).max().unwrap_or(0);
+    let mut by_size: Vec<BinaryHeap<Reverse<usize>>> = vec![BinaryHeap::new(); largest + 1];
+    for span in free.iter().filter(|s| s.size > 0) {
+        by_size[span.size].push(Reverse(span.start));
+    }
+
+    let mut moves = vec![];
+    for (id, file) in files.iter().enumerate().rev() {
+        if file.size == 0 {
+            continue;
+        }
+        let candidates = (file.size..=largest)
+            .filter_map(|size| by_size[size].peek().map(|&Reverse(start)| (start, size)))
+            .filter(|&(start, _)| start < file.start);
+        let chosen = match fit {
+            Fit::Leftmost => candidates.min(),
+            Fit::Best => candidates.min_by_key(|&(start, size)| (size, start)),
+            Fit::Worst => candidates.min_by_key(|&(start, size)| (Reverse(size), start)),
+        };
+        let Some((start, size)) = chosen else {
+            continue;
+        };
+
+        by_size[size].pop();
+        if size > file.size {
+            by_size[size - file.size].push(Reverse(start + file.size));
+        }
+        moves.push(Move {
+            id: id as u64,
+            from: file.start,
+            to: start,
+            size: file.size,
+        });
+    }
+    moves
+}
+
+/// A disk being defragmented, with where its free blocks and the blocks of
+/// each file are, and the moves made so far
+struct Reassembly {
+    blocks: Vec<Option<u64>>,
+    free: BTreeSet<usize>,
+    /// the blocks of each file, indexed by id
+    files: Vec<BTreeSet<usize>>,
+    moves: Vec<Move>,
+}
+
+impl Reassembly {
+    fn new(blocks: &[Option<u64>]) -> Self {
+        let mut free = BTreeSet::new();
+        let mut files = vec![];
+        for (i, block) in blocks.iter().enumerate() {
+            match *block {
+                Some(id) => {
+                    let id = id as usize;
+                    if files.len() <= id {
+                        files.resize(id + 1, BTreeSet::new());
+                    }
+                    files[id].insert(i);
+                }
+                None => {
+                    free.insert(i);
+                }
+            }
+        }
+        Self {
+            blocks: blocks.to_vec(),
+            free,
+            files,
+            moves: vec![],
+        }
+    }
+
+    /// moves a block of file `id` from past `past` into the free block `to`
+    fn fill(&mut self, id: usize, to: usize, past: usize) {
+        let from = *self.files[id]
+            .range(past..)
+            .next()
+            .expect("a file missing from its place has blocks past it");
+        self.shift(from, to);
+    }
+
+    /// moves a file block into a free block, joining it to the last move if
+    /// it carries on from it
+    fn shift(&mut self, from: usize, to: usize) {
+        let id = self.blocks[from].expect("only file blocks move");
+        self.blocks.swap(from, to);
+        self.free.remove(&to);
+        self.free.insert(from);
+        self.files[id as usize].remove(&from);
+        self.files[id as usize].insert(to);
+        match self.moves.last_mut() {
+            Some(m) if m.id == id && m.from + m.size == from && m.to + m.size == to => m.size += 1,
+            _ => self.moves.push(Move {
+                id,
+                from,
+                to,
+                size: 1,
+            }),
+        }
+    }
+}
+
+/// reassembles the files on a disk where they may be split up, as left by
+/// `compact_blocks`, so that they are packed from the start of the disk in id
+/// order, each in one piece.
+///
+/// Each file's place is filled in turn, from the lowest id up, the places
+/// before it being full. Its free blocks are filled first with blocks of the
+/// file from further right, leaving all the free space past the place. Then
+/// each block of a later file in the way moves right, into the first free
+/// block past the place, with the free space at the end of the disk serving
+/// as scratch, and a block of the file takes its spot. Every move is into a
+/// free block, so no block is written over
+fn defragment(blocks: &[Option<u64>]) -> Vec<Move> {
+    let mut disk = Reassembly::new(blocks);
+    let mut next = 0;
+    for id in 0..disk.files.len() {
+        let place = next..next + disk.files[id].len();
+        next = place.end;
+        let (empty, taken): (Vec<usize>, Vec<usize>) = place
+            .clone()
+            .filter(|&i| disk.blocks[i] != Some(id as u64))
+            .partition(|&i| disk.blocks[i].is_none());
+        for i in empty {
+            disk.fill(id, i, place.end);
+        }
+        for i in taken {
+            let to = *disk
+                .free
+                .range(place.end..)
+                .next()
+                .expect("a file out of place leaves free space past it");
+            disk.shift(i, to);
+            disk.fill(id, i, place.end);
+        }
+    }
+    disk.moves
+}
+
+/// A way of compacting the disk, as the moves it makes
+trait CompactionStrategy {
+    fn name(&self) -> &'static str;
+
+    fn moves(&self, filesystem: &FileSystem) -> Vec<Move>;
+}
+
+/// Moves single blocks, splitting files up (part 1)
+struct BlockLevel;
+
+impl CompactionStrategy for BlockLevel {
+    fn name(&self) -> &'static str {
+        "blocks"
+    }
+
+    fn moves(&self, filesystem: &FileSystem) -> Vec<Move> {
+        compact_blocks(&filesystem.blocks)
+    }
+}
+
+/// Moves whole files into free space to their left (part 2, with the
+/// leftmost fit)
+struct WholeFile(Fit);
+
+impl CompactionStrategy for WholeFile {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            Fit::Leftmost => "leftmost-fit",
+            Fit::Best => "best-fit",
+            Fit::Worst => "worst-fit",
+        }
+    }
+
+    fn moves(&self, filesystem: &FileSystem) -> Vec<Move> {
+        compact_files(&filesystem.files, &filesystem.free, self.0)
+    }
+}
+
+/// Compacts block by block, then makes each file contiguous again, moving
+/// blocks right where they are in another file's way
+struct Defragment;
+
+impl CompactionStrategy for Defragment {
+    fn name(&self) -> &'static str {
+        "defragment"
+    }
+
+    fn moves(&self, filesystem: &FileSystem) -> Vec<Move> {
+        let mut moves = compact_blocks(&filesystem.blocks);
+        moves.extend(defragment(&compacted(&filesystem.blocks, &moves)));
+        moves
+    }
+}
+
+const STRATEGIES: [&dyn CompactionStrategy; 5] = [
+    &BlockLevel,
+    &WholeFile(Fit::Leftmost),
+    &WholeFile(Fit::Best),
+    &WholeFile(Fit::Worst),
+    &Defragment,
+];
+
+fn compacted(blocks: &[Option<u64>], moves: &[Move]) -> Vec<Option<u64>> {
+    let mut blocks = blocks.to_vec();
+    for m in moves {
+        m.apply(&mut blocks);
+    }
+    blocks
+}
+
+/// the sum of each block's position times the id of the file in it
+fn checksum(blocks: &[Option<u64>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, id)| i as u64 * id.unwrap_or(0))
+        .sum()
+}
+
+/// the disk as in the puzzle, with each file block shown by the last digit
+/// of its id and free blocks as `.`
+fn render(blocks: &[Option<u64>]) -> String {
+    blocks
+        .iter()
+        .map(|b| match b {
+            Some(id) => char::from_digit((id % 10) as u32, 10).unwrap(),
+            None => '.',
+        })
+        .collect()
+}
+
+/// How scattered the free space is after compacting
+#[derive(Debug, PartialEq, Eq)]
+struct Fragmentation {
+    /// runs of free blocks before the last file block
+    free_spans: usize,
+    largest_free_span: usize,
+    /// files with blocks that stayed where they were
+    unmoved_files: usize,
+}
+
+impl Fragmentation {
+    fn of(filesystem: &FileSystem, blocks: &[Option<u64>], moves: &[Move]) -> Self {
+        let used = blocks
+            .iter()
+            .rposition(|b| b.is_some())
+            .map_or(0, |i| i + 1);
+        let spans: Vec<usize> = blocks[..used]
+            .split(|b| b.is_some())
+            .map(|run| run.len())
+            .filter(|&n| n > 0)
+            .collect();
+
+        let mut moved = vec![false; filesystem.files.len()];
+        for m in moves {
+            moved[m.id as usize] = true;
+        }
+        let unmoved_files = filesystem
+            .files
+            .iter()
+            .zip(&moved)
+            .filter(|(f, &moved)| f.size > 0 && !moved)
+            .count();
+
+        Self {
+            free_spans: spans.len(),
+            largest_free_span: spans.iter().copied().max().unwrap_or(0),
+            unmoved_files,
+        }
+    }
+}
+
+impl Display for Fragmentation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} free spans between files, the largest {} blocks; {} files did not move",
+            self.free_spans, self.largest_free_span, self.unmoved_files
+        )
+    }
+}
+
+impl Solution for FileSystem {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        let mut storage = vec![];
+        for l in input.numbered() {
+            let (line, l) = l?;
+            storage.extend(parse_chars(&l, line, |c| c.to_digit(10).map(|d| d as u64))?);
+        }
+        Ok(FileSystem::from(storage))
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let moves = BlockLevel.moves(self);
+        Ok(Box::new(checksum(&compacted(&self.blocks, &moves))))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let moves = WholeFile(Fit::Leftmost).moves(self);
+        Ok(Box::new(checksum(&compacted(&self.blocks, &moves))))
+    }
+}
+
+/// `compact <input> [--strategy NAME] [--replay]` compacts the disk with
+/// each strategy, or just the one named, reporting the checksum, the number of
+/// moves and how fragmented the disk is after each, and with `--replay` shows
+/// the disk after every move
+fn compact(args: &[String]) -> Result<(), AocError> {
+    let usage = || {
+        let names: Vec<&str> = STRATEGIES.iter().map(|s| s.name()).collect();
+        AocError::Usage(format!(
+            "usage: aoc-2024 compact 9 <input> [--strategy NAME] [--replay]\nstrategies: {}",
+            names.join(", ")
+        ))
+    };
+    let Some((path, mut flags)) = args.split_first() else {
+        return Err(usage());
+    };
+    let mut strategies = STRATEGIES.to_vec();
+    let mut replay = false;
+    loop {
+        match flags {
+            [] => break,
+            [flag, rest @ ..] if flag == "--replay" => {
+                replay = true;
+                flags = rest;
+            }
+            [flag, name, rest @ ..] if flag == "--strategy" => {
+                strategies.retain(|s| s.name() == name);
+                if strategies.is_empty() {
+                    return Err(usage());
+                }
+                flags = rest;
+            }
+            _ => return Err(usage()),
+        }
+    }
+
+    let path = Path::new(path);
+    let filesystem = FileSystem::parse(open_input(path)?).map_err(|e| e.in_file(path))?;
+    if replay && filesystem.blocks.len() > REPLAY_LIMIT {
+        return Err(AocError::Unsupported(format!(
+            "a disk of {} blocks is too large to replay, the limit is {}",
+            filesystem.blocks.len(),
+            REPLAY_LIMIT
+        )));
+    }
+
+    for strategy in strategies {
+        println!("{}:", strategy.name());
+        let moves = strategy.moves(&filesystem);
+        let mut blocks = filesystem.blocks.clone();
+        if replay {
+            println!("{}", render(&blocks));
+            for m in &moves {
+                m.apply(&mut blocks);
+                println!("{}", render(&blocks));
+            }
+        } else {
+            blocks = compacted(&blocks, &moves);
+        }
+        println!("checksum {} in {} moves", checksum(&blocks), moves.len());
+        println!("{}", Fragmentation::of(&filesystem, &blocks, &moves));
+        println!();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disk(map: &str) -> FileSystem {
+        FileSystem::from(
+            map.chars()
+                .map(|c| c.to_digit(10).unwrap() as u64)
+                .collect(),
+        )
+    }
+
+    fn sample() -> FileSystem {
+        disk("2333133121414131402")
+    }
+
+    fn layout(fs: &FileSystem, strategy: &dyn CompactionStrategy) -> String {
+        render(&compacted(&fs.blocks, &strategy.moves(fs)))
+    }
+
+    #[test]
+    fn renders_like_the_puzzle() {
+        let fs = sample();
+        assert_eq!(
+            render(&fs.blocks),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+
+        let moves = compact_files(&fs.files, &fs.free, Fit::Leftmost);
+        let blocks = compacted(&fs.blocks, &moves);
+        assert_eq!(
+            render(&blocks),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        assert_eq!(
+            Fragmentation::of(&fs, &blocks, &moves),
+            Fragmentation {
+                free_spans: 5,
+                largest_free_span: 5,
+                unmoved_files: 6,
+            }
+        );
+    }
+
+    #[test]
+    fn strategies_choose_different_spans() {
+        let fs = disk("1311121");
+        assert_eq!(render(&fs.blocks), "0...1.2..3");
+        assert_eq!(layout(&fs, &BlockLevel), "0321......");
+        assert_eq!(layout(&fs, &WholeFile(Fit::Leftmost)), "0321......");
+        assert_eq!(layout(&fs, &WholeFile(Fit::Best)), "021..3....");
+        assert_eq!(layout(&fs, &WholeFile(Fit::Worst)), "0321......");
+        assert_eq!(layout(&fs, &Defragment), "0123......");
+
+        let fs = disk("2333133121414131402");
+        assert_eq!(
+            layout(&fs, &WholeFile(Fit::Worst)),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        assert_eq!(Defragment.moves(&fs).len(), 54);
+    }
+
+    #[test]
+    fn defragments_by_moving_files_right() {
+        let fs = sample();
+        let fragmented = layout(&fs, &BlockLevel);
+        assert_eq!(fragmented, "0099811188827773336446555566..............");
+
+        let mut blocks = compacted(&fs.blocks, &compact_blocks(&fs.blocks));
+        let moves = defragment(&blocks);
+        // 9 sits at the start of the disk after compacting, and only reaches
+        // its place by moving right
+        assert!(moves.iter().any(|m| m.id == 9 && m.to > m.from));
+        for m in &moves {
+            assert!(blocks[m.to..m.to + m.size]
+                .iter()
+                .all(|&b| b.is_none() || b == Some(m.id)));
+            m.apply(&mut blocks);
+        }
+        assert_eq!(
+            render(&blocks),
+            "0011123334455556666777888899.............."
+        );
+        assert_eq!(layout(&fs, &Defragment), render(&blocks));
+    }
+}
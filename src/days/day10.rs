@@ -0,0 +1,109 @@
+use crate::{Answer, AocError, Day, FromChar, Grid, InputFileBuffer, Point, Solution};
+
+use std::collections::HashSet;
+
+pub const DAY: Day = Day::new::<Topography>(10);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Elevation {
+    Trailhead,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+}
+
+impl FromChar for Elevation {
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '0' => Self::Trailhead,
+            '1' => Self::One,
+            '2' => Self::Two,
+            '3' => Self::Three,
+            '4' => Self::Four,
+            '5' => Self::Five,
+            '6' => Self::Six,
+            '7' => Self::Seven,
+            '8' => Self::Eight,
+            '9' => Self::Nine,
+            _ => return None,
+        })
+    }
+}
+
+impl Elevation {
+    fn next(&self) -> Option<Self> {
+        match *self {
+            Self::Trailhead => Some(Self::One),
+            Self::One => Some(Self::Two),
+            Self::Two => Some(Self::Three),
+            Self::Three => Some(Self::Four),
+            Self::Four => Some(Self::Five),
+            Self::Five => Some(Self::Six),
+            Self::Six => Some(Self::Seven),
+            Self::Seven => Some(Self::Eight),
+            Self::Eight => Some(Self::Nine),
+            Self::Nine => None,
+        }
+    }
+}
+
+fn peaks(topo: &Grid<Elevation>, point: Point) -> Vec<Point> {
+    let elevation = &topo[point];
+    if let Some(next) = elevation.next() {
+        topo.neighbors(point)
+            .filter(|p| topo[*p] == next)
+            .flat_map(|p| peaks(topo, p))
+            .collect()
+    } else {
+        vec![point]
+    }
+}
+
+fn score(topo: &Grid<Elevation>, point: Point) -> usize {
+    HashSet::<Point>::from_iter(peaks(topo, point)).len()
+}
+
+fn rating(topo: &Grid<Elevation>, point: Point) -> usize {
+    peaks(topo, point).len()
+}
+
+pub struct Topography {
+    topo: Grid<Elevation>,
+}
+
+impl Topography {
+    fn trailheads(&self) -> impl Iterator<Item = Point> + '_ {
+        self.topo.positions(|e| *e == Elevation::Trailhead)
+    }
+}
+
+impl Solution for Topography {
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        Ok(Self {
+            topo: Grid::parse(&mut input)?,
+        })
+    }
+    fn part1(&self) -> Result<Answer, AocError> {
+        let pt1 = self
+            .trailheads()
+            .map(|p| score(&self.topo, p))
+            .sum::<usize>();
+
+        Ok(Box::new(pt1))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let pt2 = self
+            .trailheads()
+            .map(|p| rating(&self.topo, p))
+            .sum::<usize>();
+
+        Ok(Box::new(pt2))
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{parse_number, Answer, AocError, Day, InputFileBuffer, Solution};
+use std::collections::HashMap;
+
+pub const DAY: Day = Day::new::<Stones>(11);
+
+fn numbers(l: &str, line: usize) -> Result<Vec<u64>, AocError> {
+    l.split_whitespace()
+        .filter(|s| !s.is_empty())
+        .map(|s| parse_number::<u64>(s, line))
+        .collect()
+}
+
+fn blink(num: u64) -> Vec<u64> {
+    if num == 0 {
+        vec![1]
+    } else if num.ilog10() % 2 == 1 {
+        let top_half = num / 10_u64.pow(num.ilog10().div_ceil(2));
+        let bottom_half = num - (top_half * 10_u64.pow(num.ilog10().div_ceil(2)));
+        vec![top_half, bottom_half]
+    } else {
+        vec![num * 2024]
+    }
+}
+
+fn children(num: u64, steps: u64, memo: &mut HashMap<(u64, u64), Vec<u64>>) -> Vec<u64> {
+    if let Some(v) = memo.get(&(num, steps)) {
+        return v.to_vec();
+    }
+
+    if steps == 0 {
+        return vec![1];
+    }
+
+    let blinks = blink(num);
+    let mut tiers = vec![blinks.len() as u64];
+
+    if blinks.len() == 1 {
+        tiers.extend(
+            blinks
+                .iter()
+                .flat_map(|child| children(*child, steps - 1, memo)),
+        );
+    } else {
+        let descendents: Vec<Vec<u64>> = blinks
+            .iter()
+            .map(|child| children(*child, steps - 1, memo))
+            .collect();
+        tiers.extend(
+            descendents[0]
+                .iter()
+                .zip(&descendents[1])
+                .map(|(a, b)| a + b),
+        );
+    }
+
+    memo.insert((num, steps), tiers.clone());
+    tiers
+}
+
+fn copies(nums: &[u64], steps: u64) -> u64 {
+    let mut memo: HashMap<(u64, u64), Vec<u64>> = HashMap::new();
+    nums.iter()
+        .map(|num| {
+            let descendents = children(*num, steps, &mut memo);
+            descendents[descendents.len() - 1]
+        })
+        .sum()
+}
+
+pub struct Stones {
+    nums: Vec<u64>,
+}
+
+impl Solution for Stones {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        let mut nums = vec![];
+        for l in input.numbered() {
+            let (line, l) = l?;
+            nums.extend(numbers(&l, line)?);
+        }
+        Ok(Self { nums })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(copies(&self.nums, 25)))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(Box::new(copies(&self.nums, 75)))
+    }
+}
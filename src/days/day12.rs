@@ -0,0 +1,255 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Answer, AocError, Day, Direction, Grid, InputFileBuffer, Point, Solution};
+
+pub const DAY: Day = Day::new::<Garden>(12);
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    visited: bool,
+}
+
+#[derive(Clone)]
+struct Plot {
+    position: Point,
+    perimeter: usize,
+    edges: HashMap<Direction, Edge>,
+}
+
+#[derive(Clone)]
+struct PlotGroup {
+    plots: Vec<Plot>,
+    points: HashSet<Point>,
+}
+
+impl PlotGroup {
+    fn cost(&self) -> usize {
+        let area = self.plots.len();
+        area * self.plots.iter().map(|p| p.perimeter).sum::<usize>()
+    }
+
+    fn area(&self) -> usize {
+        self.plots.len()
+    }
+
+    fn corners(&mut self) -> i32 {
+        let mut corners = 0;
+        loop {
+            // points with top perimeter that has not been visited
+            let candidate_starts = self.plots.iter().filter_map(|p| {
+                if let Some(edge) = p.edges.get(&Direction::Up) {
+                    if !edge.visited {
+                        return Some(p.position);
+                    }
+                    None
+                } else {
+                    None
+                }
+            });
+
+            if candidate_starts.clone().count() == 0 {
+                break;
+            }
+
+            let start = candidate_starts.clone().next().unwrap();
+            corners += walkaround(self, start);
+        }
+
+        corners
+    }
+}
+
+fn neighbors(point: Point) -> Vec<(Direction, Point)> {
+    [
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+        Direction::Up,
+    ]
+    .iter()
+    .map(|d| (*d, point + *d))
+    .collect()
+}
+
+fn build_edges(external_directions: &[Direction]) -> HashMap<Direction, Edge> {
+    let mut edges = HashMap::new();
+    for dir in external_directions {
+        edges.insert(*dir, Edge { visited: false });
+    }
+    edges
+}
+
+fn visit(garden: &Grid<char>, start: Point) -> PlotGroup {
+    let mut frontier = vec![start];
+    let mut explored: HashSet<Point> = HashSet::new();
+    let mut plots = vec![];
+    let plot_type = garden[start];
+    while let Some(next) = frontier.pop() {
+        if explored.contains(&next) {
+            continue;
+        }
+        // Determine how many external edges the plot has and add to plots. Then,
+        // add its neighbors that are the same plot type to frontier
+        let adj = neighbors(next);
+        let external_adj: Vec<Direction> = adj
+            .iter()
+            .filter_map(|(d, p)| {
+                // is external edge if neighbor is out of bounds, or if neighbor is
+                // a different plot type
+                if garden.get(*p) != Some(&plot_type) {
+                    Some(*d)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let adj_friend: Vec<(Direction, Point)> = adj
+            .iter()
+            .filter(|(_, p)| garden.get(*p) == Some(&plot_type))
+            .copied()
+            .collect();
+
+        let edges = build_edges(&external_adj);
+
+        plots.push(Plot {
+            position: next,
+            perimeter: adj.len() - adj_friend.len(),
+            edges,
+        });
+
+        frontier.extend(adj_friend.iter().map(|(_, p)| p));
+        explored.insert(next);
+    }
+    let points = HashSet::from_iter(plots.iter().map(|p| p.position));
+    PlotGroup { plots, points }
+}
+
+fn explore(garden: &Grid<char>) -> Vec<PlotGroup> {
+    let mut explored: HashSet<Point> = HashSet::new();
+    let mut plot_groups = vec![];
+    for p in garden.points() {
+        if explored.contains(&p) {
+            continue;
+        }
+        let group = visit(garden, p);
+        explored.extend(group.points.iter());
+        plot_groups.push(group);
+    }
+
+    plot_groups
+}
+
+fn should_turn_left(group: &PlotGroup, direction: &Direction, position: &Point) -> bool {
+    let next = *position + *direction;
+
+    // if next position is in the garden group, then turn left, otherwise, do not.
+    // out of bounds positions are never in the group
+    group.points.contains(&next)
+}
+
+fn should_turn_right(group: &PlotGroup, direction: &Direction, position: &Point) -> bool {
+    // turn right if the square to the right of the next square is
+    // not in the group, including when it would be out of bounds
+    !group
+        .points
+        .contains(&(*position + *direction + direction.turn_clockwise()))
+}
+
+fn turn_left(position: Point, direction: &Direction) -> (Point, Direction) {
+    (position, direction.turn_counter_clockwise())
+}
+
+fn turn_right(position: Point, direction: &Direction) -> (Point, Direction) {
+    let right = direction.turn_clockwise();
+    (position + *direction + right, right)
+}
+
+fn go_straight(position: Point, direction: &Direction) -> (Point, Direction) {
+    (position + *direction, *direction)
+}
+
+fn mark_visited(group: &mut PlotGroup, direction: &Direction, position: Point) {
+    let visited = position + direction.turn_clockwise();
+    let plot = group
+        .plots
+        .iter_mut()
+        .find(|p| p.position == visited)
+        .unwrap();
+    let edge_direction = direction.turn_clockwise().opposite();
+    plot.edges.insert(edge_direction, Edge { visited: true });
+}
+
+fn visited(group: &mut PlotGroup, direction: &Direction, position: Point) -> bool {
+    let visited = position + direction.turn_clockwise();
+    let plot = group.plots.iter().find(|p| p.position == visited).unwrap();
+    let edge_direction = direction.turn_clockwise().opposite();
+    if let Some(edge) = plot.edges.get(&edge_direction) {
+        edge.visited
+    } else {
+        false
+    }
+}
+
+/// walks the perimeter of a garden group and counts the number of corners
+fn walkaround(group: &mut PlotGroup, start: Point) -> i32 {
+    // assume that we start above the top left element of a garden group, by
+    // construction
+
+    // position above the first plot square. This could be out of bounds
+    let mut walker = start + Direction::Up;
+    let mut direction = Direction::Right;
+    let mut corners = 0;
+
+    // at every step, we can either walk forward, turn left, or turn right
+    // we turn left if the next tile is in th garden group -> meaning we have
+    // run into a wall
+    // we turn right if we would walk out of bounds, or if there would not be
+    // a group element to the right if we go straigh
+    //
+    // we mark the perimeter edge that we were touching as visited
+    loop {
+        if visited(group, &direction, walker) {
+            break;
+        }
+        mark_visited(group, &direction, walker);
+        if should_turn_left(group, &direction, &walker) {
+            (walker, direction) = turn_left(walker, &direction);
+            corners += 1;
+        } else if should_turn_right(group, &direction, &walker) {
+            (walker, direction) = turn_right(walker, &direction);
+            corners += 1;
+        } else {
+            (walker, direction) = go_straight(walker, &direction);
+        }
+    }
+
+    corners
+}
+
+pub struct Garden {
+    groups: Vec<PlotGroup>,
+}
+
+impl Solution for Garden {
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        let garden: Grid<char> = Grid::parse(&mut input)?;
+        let groups = explore(&garden);
+        Ok(Self { groups })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let pt1 = self.groups.iter().map(|g| g.cost()).sum::<usize>();
+        Ok(Box::new(pt1))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut pt2 = 0;
+        for group in &self.groups {
+            let mut group_clone = group.clone();
+            let area = group.area();
+            let corners = group_clone.corners() as usize;
+            pt2 += area * corners;
+        }
+        Ok(Box::new(pt2))
+    }
+}
@@ -0,0 +1,627 @@
+use std::path::Path;
+
+use crate::{open_input, parse_number, Answer, AocError, Day, InputFileBuffer, Mode, Solution};
+
+pub const DAY: Day = Day::new::<Arcade>(13).with_modes(&[Mode {
+    name: "claws",
+    usage: "<input> [--costs A,B,..] [--limit N|none] [--offset N]",
+    run: claws,
+}]);
+
+#[derive(Debug)]
+struct Button {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug)]
+struct Claw {
+    buttons: Vec<Button>,
+    prize: (i64, i64),
+}
+
+/// How the arcade charges for presses and where it puts the prizes
+#[derive(Debug, Clone)]
+struct Rules {
+    /// tokens per press of each button, in the order the buttons are listed
+    costs: Vec<i64>,
+    /// the most times any one button may be pressed
+    limit: Option<i64>,
+    /// added to both coordinates of every prize
+    offset: i64,
+}
+
+impl Rules {
+    fn part1() -> Self {
+        Self {
+            costs: vec![3, 1],
+            limit: Some(100),
+            offset: 0,
+        }
+    }
+
+    fn part2() -> Self {
+        Self {
+            costs: vec![3, 1],
+            limit: None,
+            offset: 10_000_000_000_000,
+        }
+    }
+}
+
+// the most combinations of extra button presses tried for one machine
+const SEARCH_LIMIT: i128 = 10_000_000;
+
+fn floor_div(n: i128, d: i128) -> i128 {
+    let q = n / d;
+    if n % d != 0 && (n < 0) != (d < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn ceil_div(n: i128, d: i128) -> i128 {
+    -floor_div(-n, d)
+}
+
+/// `(g, x, y)` with `u * x + v * y == g`, the non-negative gcd of `u` and `v`
+fn extended_gcd(u: i128, v: i128) -> (i128, i128, i128) {
+    if v == 0 {
+        (u.abs(), u.signum(), 0)
+    } else {
+        let (g, x, y) = extended_gcd(v, u % v);
+        (g, y, x - (u / v) * y)
+    }
+}
+
+/// The integers `t` that satisfy every constraint added so far, where `None`
+/// is unbounded
+struct Interval {
+    lo: Option<i128>,
+    hi: Option<i128>,
+    empty: bool,
+}
+
+impl Interval {
+    /// keeps the `t` with `base + t * step >= min`
+    fn at_least(&mut self, base: i128, step: i128, min: i128) {
+        match step {
+            0 => self.empty |= base < min,
+            step if step > 0 => {
+                let t = ceil_div(min - base, step);
+                self.lo = Some(self.lo.map_or(t, |lo| lo.max(t)));
+            }
+            step => {
+                let t = floor_div(min - base, step);
+                self.hi = Some(self.hi.map_or(t, |hi| hi.min(t)));
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.empty || matches!((self.lo, self.hi), (Some(lo), Some(hi)) if lo > hi)
+    }
+}
+
+/// the cheapest non-negative `(a, b)`, each at most `limit`, with
+/// `a * u + b * v == w`, where each unit of `a` and `b` costs `costs`
+fn cheapest_on_line(
+    u: i128,
+    v: i128,
+    w: i128,
+    costs: (i64, i64),
+    limit: Option<i64>,
+) -> Option<(i128, i128)> {
+    let (g, x, y) = extended_gcd(u, v);
+    if g == 0 {
+        return (w == 0).then_some((0, 0));
+    }
+    if w % g != 0 {
+        return None;
+    }
+
+    // every solution is (a0 + t * da, b0 + t * db)
+    let (a0, b0) = (x * (w / g), y * (w / g));
+    let (da, db) = (v / g, -u / g);
+    let mut interval = Interval {
+        lo: None,
+        hi: None,
+        empty: false,
+    };
+    interval.at_least(a0, da, 0);
+    interval.at_least(b0, db, 0);
+    if let Some(limit) = limit {
+        interval.at_least(-a0, -da, -(limit as i128));
+        interval.at_least(-b0, -db, -(limit as i128));
+    }
+    if interval.is_empty() {
+        return None;
+    }
+
+    // the cost changes by `slope` with each step of `t`; pressing a button
+    // costs tokens, so the cost only falls towards a bounded end
+    let slope = costs.0 as i128 * da + costs.1 as i128 * db;
+    let t = match (slope, interval.lo, interval.hi) {
+        (slope, _, Some(hi)) if slope < 0 => hi,
+        (_, Some(lo), _) => lo,
+        (_, None, Some(hi)) => hi,
+        (_, None, None) => unreachable!("a press count is bounded below by zero"),
+    };
+    Some((a0 + t * da, b0 + t * db))
+}
+
+/// the cheapest presses of `a` and `b`, costing `costs` tokens each and each
+/// pressed at most `limit` times, that move the claw to `prize`
+fn two_buttons(
+    a: &Button,
+    b: &Button,
+    costs: (i64, i64),
+    prize: (i64, i64),
+    limit: Option<i64>,
+) -> Option<(i64, i64)> {
+    let (ax, ay, bx, by) = (a.x as i128, a.y as i128, b.x as i128, b.y as i128);
+    let (px, py) = (prize.0 as i128, prize.1 as i128);
+
+    let det = ax * by - ay * bx;
+    let (na, nb) = if det != 0 {
+        // the buttons move in different directions, so there is exactly one
+        // way to reach the prize, if any
+        let (na, nb) = (px * by - py * bx, ax * py - ay * px);
+        if na % det != 0 || nb % det != 0 {
+            return None;
+        }
+        let (na, nb) = (na / det, nb / det);
+        let limit = limit.map_or(i128::MAX, |l| l as i128);
+        if na < 0 || nb < 0 || na > limit || nb > limit {
+            return None;
+        }
+        (na, nb)
+    } else {
+        // the buttons move along one line, which the prize must be on
+        let (dx, dy) = if (ax, ay) != (0, 0) {
+            (ax, ay)
+        } else {
+            (bx, by)
+        };
+        if dx * py - dy * px != 0 || ((dx, dy) == (0, 0) && (px, py) != (0, 0)) {
+            return None;
+        }
+        // so only the position along one axis that the line crosses matters
+        if dx != 0 {
+            cheapest_on_line(ax, bx, px, costs, limit)?
+        } else {
+            cheapest_on_line(ay, by, py, costs, limit)?
+        }
+    };
+    Some((i64::try_from(na).ok()?, i64::try_from(nb).ok()?))
+}
+
+/// the presses of `button`, at most `limit`, that move the claw to `prize`
+fn one_button(button: &Button, prize: (i64, i64), limit: Option<i64>) -> Option<i64> {
+    let presses = match (button.x, button.y) {
+        (0, 0) => 0,
+        (0, y) => prize.1 / y,
+        (x, _) => prize.0 / x,
+    };
+    let reaches = (presses * button.x, presses * button.y) == prize;
+    (reaches && presses >= 0 && limit.is_none_or(|l| presses <= l)).then_some(presses)
+}
+
+// every combination of presses below `bounds`, in order
+fn combinations(bounds: &[i64]) -> impl Iterator<Item = Vec<i64>> + '_ {
+    let mut next = Some(vec![0; bounds.len()]);
+    std::iter::from_fn(move || {
+        let current = next.take()?;
+        let mut counts = current.clone();
+        for (count, &bound) in counts.iter_mut().zip(bounds) {
+            *count += 1;
+            if *count < bound {
+                next = Some(counts);
+                break;
+            }
+            *count = 0;
+        }
+        Some(current)
+    })
+}
+
+fn cost(presses: &[i64], costs: &[i64]) -> i64 {
+    presses.iter().zip(costs).map(|(n, c)| n * c).sum()
+}
+
+/// the cheapest presses of three or more buttons, as an integer program: the
+/// presses of every button after the first two are tried in turn, each
+/// leaving a two button machine to solve exactly
+fn many_buttons(
+    buttons: &[Button],
+    costs: &[i64],
+    prize: (i64, i64),
+    limit: Option<i64>,
+) -> Result<Option<Vec<i64>>, String> {
+    let pair = (costs[0], costs[1]);
+
+    // pressing any two buttons alone gives an upper bound on the cost
+    let mut best: Option<Vec<i64>> = None;
+    for i in 0..buttons.len() {
+        for j in i + 1..buttons.len() {
+            let Some((ni, nj)) =
+                two_buttons(&buttons[i], &buttons[j], (costs[i], costs[j]), prize, limit)
+            else {
+                continue;
+            };
+            let mut presses = vec![0; buttons.len()];
+            (presses[i], presses[j]) = (ni, nj);
+            if best
+                .as_ref()
+                .is_none_or(|b| cost(&presses, costs) < cost(b, costs))
+            {
+                best = Some(presses);
+            }
+        }
+    }
+
+    let bounds = costs[2..]
+        .iter()
+        .map(|&c| {
+            let by_cost = best.as_ref().filter(|_| c > 0).map(|b| cost(b, costs) / c);
+            match (limit, by_cost) {
+                (Some(l), Some(n)) => Some(l.min(n) + 1),
+                (Some(n), None) | (None, Some(n)) => Some(n + 1),
+                (None, None) => None,
+            }
+        })
+        .collect::<Option<Vec<i64>>>()
+        .ok_or("more than two buttons need a press limit or a cost")?;
+    let combinations_count = bounds.iter().map(|&b| b as i128).product::<i128>();
+    if combinations_count > SEARCH_LIMIT {
+        return Err(format!(
+            "{} combinations of presses are too many to search",
+            combinations_count
+        ));
+    }
+
+    for extra in combinations(&bounds) {
+        let moved = buttons[2..]
+            .iter()
+            .zip(&extra)
+            .fold((0, 0), |(x, y), (b, n)| (x + b.x * n, y + b.y * n));
+        let rest = (prize.0 - moved.0, prize.1 - moved.1);
+        let Some((a, b)) = two_buttons(&buttons[0], &buttons[1], pair, rest, limit) else {
+            continue;
+        };
+        let presses = [vec![a, b], extra].concat();
+        if best
+            .as_ref()
+            .is_none_or(|b| cost(&presses, costs) < cost(b, costs))
+        {
+            best = Some(presses);
+        }
+    }
+    Ok(best)
+}
+
+impl Claw {
+    /// the cheapest presses of each button that win the prize under `rules`
+    fn presses(&self, rules: &Rules) -> Result<Option<Vec<i64>>, String> {
+        let costs = &rules.costs;
+        if costs.len() < self.buttons.len() {
+            return Err(format!(
+                "a machine has {} buttons, but only {} costs are known",
+                self.buttons.len(),
+                costs.len()
+            ));
+        }
+        let prize = (self.prize.0 + rules.offset, self.prize.1 + rules.offset);
+        let presses = match &self.buttons[..] {
+            [a] => one_button(a, prize, rules.limit).map(|n| vec![n]),
+            [a, b] => {
+                two_buttons(a, b, (costs[0], costs[1]), prize, rules.limit).map(|(a, b)| vec![a, b])
+            }
+            buttons => many_buttons(buttons, costs, prize, rules.limit)?,
+        };
+        Ok(presses)
+    }
+}
+
+impl Arcade {
+    /// how many prizes can be won and the fewest tokens that win them all
+    fn play(&self, rules: &Rules) -> Result<(usize, i64), String> {
+        let mut won = 0;
+        let mut tokens = 0;
+        for claw in &self.claws {
+            if let Some(presses) = claw.presses(rules)? {
+                won += 1;
+                tokens += cost(&presses, &rules.costs);
+            }
+        }
+        Ok((won, tokens))
+    }
+
+    fn tokens(&self, rules: &Rules) -> Result<Answer, AocError> {
+        let (_, tokens) = self.play(rules).map_err(AocError::Unsupported)?;
+        Ok(Box::new(tokens))
+    }
+}
+
+/// the `X.., Y..` offsets following `prefix`, where each is the axis, then
+/// `separator`, then a number of any length and sign
+fn parse_offsets(
+    l: &str,
+    prefix: &str,
+    separator: &str,
+    line: usize,
+) -> Result<(i64, i64), AocError> {
+    // a button's offsets are written with their sign, as in `X+94`
+    let shown = if separator.is_empty() { "+" } else { separator };
+    let expected = || AocError::expected(line, format!("{} X{}.., Y{}..", prefix, shown, shown));
+    let (x, y) = l
+        .trim_end()
+        .strip_prefix(prefix)
+        .and_then(|rest| rest.split_once(','))
+        .ok_or_else(expected)?;
+    let offset = |s: &str, axis: &str| {
+        let value = s
+            .trim()
+            .strip_prefix(axis)
+            .and_then(|s| s.strip_prefix(separator))
+            .ok_or_else(expected)?;
+        parse_number::<i64>(value, line)
+    };
+    Ok((offset(x, "X")?, offset(y, "Y")?))
+}
+
+fn parse_button(l: &str, name: &str, line: usize) -> Result<Button, AocError> {
+    let (x, y) = parse_offsets(l, &format!("Button {}:", name), "", line)?;
+    Ok(Button { x, y })
+}
+
+/// the names of a machine's buttons, in the order they are listed
+const BUTTON_NAMES: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// the button listed after `count` others, reporting the line found when it
+/// is neither that button nor, once there is a button, the prize
+fn next_button(l: &str, count: usize, line: usize) -> Result<Button, AocError> {
+    let name = BUTTON_NAMES.chars().nth(count);
+    if let Some(name) = name.filter(|name| l.starts_with(&format!("Button {}:", name))) {
+        return parse_button(l, &name.to_string(), line);
+    }
+    let expected = match name {
+        None => {
+            return Err(AocError::parse(
+                line,
+                format!("more than {} buttons", count),
+            ))
+        }
+        Some(name) if count == 0 => format!("`Button {}:`", name),
+        Some(name) => format!("`Button {}:` or `Prize:`", name),
+    };
+    let found = match l.trim() {
+        "" => "a blank line".to_string(),
+        l => format!("`{}`", l),
+    };
+    Err(AocError::parse(
+        line,
+        format!("expected {}, found {}", expected, found),
+    ))
+}
+
+pub struct Arcade {
+    claws: Vec<Claw>,
+}
+
+impl Solution for Arcade {
+    /// machines of `Button A:`, `Button B:`, and so on, followed by a
+    /// `Prize:` line, separated by any number of blank lines
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        let mut claws = vec![];
+        while let Some(l) = input.next() {
+            let mut l = l?;
+            if l.trim().is_empty() {
+                continue;
+            }
+            let mut buttons = vec![];
+            while buttons.is_empty() || !l.starts_with("Prize:") {
+                let button = next_button(&l, buttons.len(), input.line())?;
+                buttons.push(button);
+                l = input.next_line("Prize:")?;
+            }
+            let prize = parse_offsets(&l, "Prize:", "=", input.line())?;
+
+            claws.push(Claw { buttons, prize });
+        }
+        Ok(Self { claws })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        self.tokens(&Rules::part1())
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        self.tokens(&Rules::part2())
+    }
+}
+
+// parses `--costs 3,1,..`, `--limit N|none` and `--offset N` over the rules
+// of part 1
+fn parse_rules(mut flags: &[String]) -> Result<Rules, AocError> {
+    let mut rules = Rules::part1();
+    while let [flag, value, rest @ ..] = flags {
+        let invalid = || AocError::Usage(format!("invalid value `{}` for {}", value, flag));
+        match flag.as_str() {
+            "--costs" => {
+                rules.costs = value
+                    .split(',')
+                    .map(|c| c.trim().parse::<i64>().ok().filter(|&c| c >= 0))
+                    .collect::<Option<_>>()
+                    .ok_or_else(invalid)?
+            }
+            "--limit" if value == "none" => rules.limit = None,
+            "--limit" => {
+                rules.limit = Some(
+                    value
+                        .parse()
+                        .ok()
+                        .filter(|&l: &i64| l >= 0)
+                        .ok_or_else(invalid)?,
+                )
+            }
+            "--offset" => rules.offset = value.parse().map_err(|_| invalid())?,
+            _ => return Err(AocError::Usage(format!("unknown option `{}`", flag))),
+        }
+        flags = rest;
+    }
+    if let [flag] = flags {
+        return Err(AocError::Usage(format!("{} needs a value", flag)));
+    }
+    Ok(rules)
+}
+
+/// `claws <input> [--costs A,B,..] [--limit N|none] [--offset N]` plays every
+/// machine under rules other than the puzzle's, which default to part 1's
+fn claws(args: &[String]) -> Result<(), AocError> {
+    let Some((path, flags)) = args.split_first() else {
+        return Err(AocError::Usage(
+            "usage: aoc-2024 claws 13 <input> [--costs A,B,..] [--limit N|none] [--offset N]"
+                .into(),
+        ));
+    };
+    let rules = parse_rules(flags)?;
+    let path = Path::new(path);
+    let arcade = Arcade::parse(open_input(path)?).map_err(|e| e.in_file(path))?;
+
+    let (won, tokens) = arcade.play(&rules).map_err(AocError::Unsupported)?;
+    println!(
+        "{} of {} prizes won for {} tokens",
+        won,
+        arcade.claws.len(),
+        tokens
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Option<(i64, i64)> {
+        let (a, b) = (Button { x: a.0, y: a.1 }, Button { x: b.0, y: b.1 });
+        two_buttons(&a, &b, (3, 1), prize, Some(100))
+    }
+
+    #[test]
+    fn parses_any_offsets() {
+        let button = parse_button("Button A: X+7, Y-1234", "A", 1).unwrap();
+        assert_eq!((button.x, button.y), (7, -1234));
+        assert_eq!(
+            parse_offsets("Prize: X=-5, Y=123456", "Prize:", "=", 3).unwrap(),
+            (-5, 123456)
+        );
+        let err = parse_button("Button B: X+1, Z+2", "B", 2).unwrap_err();
+        assert_eq!(err.to_string(), "2: expected `Button B: X+.., Y+..`");
+    }
+
+    #[test]
+    fn reports_what_is_found_in_place_of_a_button() {
+        let message = |l, count| next_button(l, count, 4).unwrap_err().to_string();
+        assert_eq!(
+            message("Prize: X=1, Y=2", 0),
+            "4: expected `Button A:`, found `Prize: X=1, Y=2`"
+        );
+        assert_eq!(
+            message("", 1),
+            "4: expected `Button B:` or `Prize:`, found a blank line"
+        );
+        assert_eq!(
+            message("Button D: X+1, Y+2", 2),
+            "4: expected `Button C:` or `Prize:`, found `Button D: X+1, Y+2`"
+        );
+        assert_eq!(message("Button A: X+1, Y+2", 26), "4: more than 26 buttons");
+    }
+
+    #[test]
+    fn independent_buttons() {
+        assert_eq!(solve((94, 34), (22, 67), (8400, 5400)), Some((80, 40)));
+        assert_eq!(solve((26, 66), (67, 21), (12748, 12176)), None);
+        // reachable, but only with more presses than allowed
+        assert_eq!(solve((1, 0), (0, 1), (101, 5)), None);
+    }
+
+    #[test]
+    fn parallel_buttons() {
+        // B moves further per token, so it is pressed as much as possible
+        assert_eq!(solve((1, 1), (2, 2), (10, 10)), Some((0, 5)));
+        // A moves further per token
+        assert_eq!(solve((4, 4), (1, 1), (13, 13)), Some((3, 1)));
+        // the gcd of the steps does not divide the distance
+        assert_eq!(solve((2, 4), (4, 8), (5, 10)), None);
+        // the prize is off the line
+        assert_eq!(solve((1, 1), (2, 2), (10, 11)), None);
+        // the cheap button overshoots, so A makes up the difference
+        assert_eq!(solve((3, 0), (5, 0), (7, 0)), None);
+        assert_eq!(solve((3, 0), (5, 0), (11, 0)), Some((2, 1)));
+        // the press limit forces the expensive button
+        assert_eq!(solve((1, 0), (1, 0), (150, 0)), Some((50, 100)));
+    }
+
+    #[test]
+    fn buttons_that_do_not_move() {
+        assert_eq!(solve((0, 0), (0, 0), (0, 0)), Some((0, 0)));
+        assert_eq!(solve((0, 0), (0, 0), (1, 0)), None);
+        assert_eq!(solve((0, 0), (0, 3), (0, 9)), Some((0, 3)));
+        assert_eq!(solve((0, 2), (0, 0), (0, 9)), None);
+    }
+
+    #[test]
+    fn buttons_in_opposite_directions() {
+        // A undoes B, so the prize is only reached by the cheapest balance
+        assert_eq!(solve((-1, -1), (2, 2), (3, 3)), Some((1, 2)));
+    }
+
+    fn claw(buttons: &[(i64, i64)], prize: (i64, i64)) -> Claw {
+        Claw {
+            buttons: buttons.iter().map(|&(x, y)| Button { x, y }).collect(),
+            prize,
+        }
+    }
+
+    #[test]
+    fn one_button() {
+        let rules = Rules::part1();
+        assert_eq!(claw(&[(2, 3)], (8, 12)).presses(&rules), Ok(Some(vec![4])));
+        assert_eq!(claw(&[(2, 3)], (8, 13)).presses(&rules), Ok(None));
+    }
+
+    #[test]
+    fn three_buttons() {
+        let rules = Rules {
+            costs: vec![3, 1, 1],
+            ..Rules::part1()
+        };
+        let machine = claw(&[(1, 0), (0, 1), (1, 1)], (5, 7));
+        assert_eq!(machine.presses(&rules), Ok(Some(vec![0, 2, 5])));
+
+        // without a press limit, the cheapest pair bounds the search
+        let rules = Rules {
+            limit: None,
+            ..rules
+        };
+        let machine = claw(&[(1, 0), (0, 1), (1, 1)], (50, 70));
+        assert_eq!(machine.presses(&rules), Ok(Some(vec![0, 20, 50])));
+
+        let machine = claw(&[(1, 0), (0, 1), (1, 1)], (1, 1));
+        assert!(machine.presses(&Rules::part1()).is_err());
+    }
+
+    #[test]
+    fn unknown_costs_are_errors() {
+        let arcade = Arcade {
+            claws: vec![claw(&[(1, 0), (0, 1), (1, 1)], (1, 1))],
+        };
+        let err = arcade.part1().err().expect("a third button has no cost");
+        assert_eq!(
+            err.to_string(),
+            "unsupported: a machine has 3 buttons, but only 2 costs are known"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    ops::Range,
+    path::Path,
+};
+
+use crate::{
+    open_input, parse_number, tile, write_pgm, write_ppm, Answer, AocError, Day, GifWriter, Grid,
+    InputFileBuffer, Mode, Point, Solution,
+};
+
+pub const DAY: Day = Day::new::<Robots>(14).with_modes(&[Mode {
+    name: "frames",
+    usage: "<input> <output.pgm|.ppm|.gif> [--steps FROM..TO]",
+    run: frames,
+}]);
+
+// the room and step count of the puzzle, unless the input says otherwise
+const AREA_WIDTH: i32 = 101;
+const AREA_HEIGHT: i32 = 103;
+const STEPS: i32 = 100;
+
+#[derive(Debug)]
+struct Robot {
+    starting_position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+fn parse_pair(s: &str, prefix: &str, line: usize) -> Result<(i32, i32), AocError> {
+    let Some((j, i)) = s.strip_prefix(prefix).and_then(|s| s.split_once(",")) else {
+        return Err(AocError::expected(line, format!("{}X,Y", prefix)));
+    };
+    Ok((parse_number::<i32>(i, line)?, parse_number::<i32>(j, line)?))
+}
+
+impl Robot {
+    fn parse(s: &str, line: usize) -> Result<Self, AocError> {
+        let Some((pos, vel)) = s.split_once(" ") else {
+            return Err(AocError::expected(line, "p=X,Y v=X,Y"));
+        };
+        let starting_position = parse_pair(pos, "p=", line)?;
+        let velocity = parse_pair(vel, "v=", line)?;
+
+        Ok(Self {
+            starting_position,
+            velocity,
+        })
+    }
+}
+
+impl Robot {
+    fn terminal_position(&self, steps: i32, area_width: i32, area_height: i32) -> (i32, i32) {
+        let (i, j) = self.starting_position;
+        let (di, dj) = self.velocity;
+
+        // in 64 bits so that a long run cannot overflow
+        let wrap = |start: i32, velocity: i32, size: i32| {
+            (start as i64 + steps as i64 * velocity as i64).rem_euclid(size as i64) as i32
+        };
+
+        (wrap(i, di, area_height), wrap(j, dj, area_width))
+    }
+}
+
+/// the product of the robot counts in each quadrant, ignoring the middle row
+/// and column, which only exist if both dimensions are odd
+fn quadrant_score(
+    robots: &[Robot],
+    steps: i32,
+    area_height: i32,
+    area_width: i32,
+) -> Result<i32, AocError> {
+    if area_height % 2 == 0 || area_width % 2 == 0 {
+        return Err(AocError::Unsupported(format!(
+            "a {}x{} room has no middle row and column to split quadrants on",
+            area_width, area_height
+        )));
+    }
+    let (mut a, mut b, mut c, mut d) = (0, 0, 0, 0);
+    for (i, j) in robots
+        .iter()
+        .map(|r| r.terminal_position(steps, area_width, area_height))
+    {
+        if i < area_height / 2 && j < area_width / 2 {
+            a += 1;
+        }
+        if i < area_height / 2 && j > area_width / 2 {
+            b += 1;
+        }
+        if i > area_height / 2 && j < area_width / 2 {
+            c += 1;
+        }
+        if i > area_height / 2 && j > area_width / 2 {
+            d += 1;
+        }
+    }
+    Ok(a * b * c * d)
+}
+
+// n² times the variance of `values`, which is smallest when they bunch up
+fn spread(values: impl Iterator<Item = i32>) -> i64 {
+    let (mut n, mut sum, mut squares) = (0i64, 0i64, 0i64);
+    for v in values.map(i64::from) {
+        n += 1;
+        sum += v;
+        squares += v * v;
+    }
+    n * squares - sum * sum
+}
+
+// the step within one period of an axis at which the robots bunch up most
+// along that axis
+fn tightest_step(robots: &[Robot], period: i32, axis: impl Fn(&Robot, i32) -> i32) -> i32 {
+    (0..period)
+        .min_by_key(|&steps| spread(robots.iter().map(|r| axis(r, steps))))
+        .unwrap_or(0)
+}
+
+// the step below `m * n` that is `a` mod `m` and `b` mod `n`, by the Chinese
+// remainder theorem, when `m` and `n` are coprime
+fn chinese_remainder(a: i32, m: i32, b: i32, n: i32) -> Option<i32> {
+    (0..n).map(|k| a + k * m).find(|t| t % n == b)
+}
+
+/// the first step at which the robots draw a picture. Each robot's column
+/// repeats every `area_width` steps and its row every `area_height` steps, so
+/// the picture is found by taking the step at which each axis is least spread
+/// out, then combining the two
+fn picture_step(robots: &[Robot], area_height: i32, area_width: i32) -> Option<i32> {
+    let column = tightest_step(robots, area_width, |r, steps| {
+        r.terminal_position(steps, area_width, area_height).1
+    });
+    let row = tightest_step(robots, area_height, |r, steps| {
+        r.terminal_position(steps, area_width, area_height).0
+    });
+    chinese_remainder(column, area_width, row, area_height)
+}
+
+/// how many robots stand on each tile after `steps`, as brightness relative
+/// to the most crowded tile
+fn density(robots: &[Robot], steps: i32, area_height: i32, area_width: i32) -> Grid<u8> {
+    let mut counts = Grid::new(area_width as usize, area_height as usize, 0u32);
+    for robot in robots {
+        let (i, j) = robot.terminal_position(steps, area_width, area_height);
+        counts[Point::new(i, j)] += 1;
+    }
+    let most = counts.iter().map(|(_, &n)| n).max().unwrap_or(0).max(1);
+    counts.map(|&n| (n * 255 / most) as u8)
+}
+
+pub struct Robots {
+    robots: Vec<Robot>,
+    area_width: i32,
+    area_height: i32,
+    steps: i32,
+}
+
+// parses `WxH`
+fn parse_area(s: &str, line: usize) -> Result<(i32, i32), AocError> {
+    let Some((width, height)) = s.split_once('x') else {
+        return Err(AocError::expected(line, "area: WIDTHxHEIGHT"));
+    };
+    let (width, height) = (parse_number(width, line)?, parse_number(height, line)?);
+    if width <= 0 || height <= 0 {
+        return Err(AocError::parse(
+            line,
+            format!("the room cannot be {}x{}", width, height),
+        ));
+    }
+    Ok((width, height))
+}
+
+impl Solution for Robots {
+    /// robots, one per line, optionally preceded by `area: WxH` and
+    /// `steps: N` header lines for rooms other than the puzzle's
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        let mut area = (AREA_WIDTH, AREA_HEIGHT);
+        let mut steps = STEPS;
+        let mut robots = vec![];
+        for l in input.numbered() {
+            let (i, l) = l?;
+            match l.split_once(':') {
+                Some(("area", value)) if robots.is_empty() => area = parse_area(value.trim(), i)?,
+                Some(("steps", value)) if robots.is_empty() => steps = parse_number(value, i)?,
+                _ => robots.push((i, Robot::parse(&l, i)?)),
+            }
+        }
+
+        let (area_width, area_height) = area;
+        for (i, robot) in &robots {
+            let (row, column) = robot.starting_position;
+            if !(0..area_height).contains(&row) || !(0..area_width).contains(&column) {
+                return Err(AocError::parse(
+                    *i,
+                    format!(
+                        "robot starts at {},{}, outside the {}x{} room",
+                        column, row, area_width, area_height
+                    ),
+                ));
+            }
+        }
+
+        Ok(Self {
+            robots: robots.into_iter().map(|(_, r)| r).collect(),
+            area_width,
+            area_height,
+            steps,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let score = quadrant_score(&self.robots, self.steps, self.area_height, self.area_width)?;
+        Ok(Box::new(score))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        Ok(
+            match picture_step(&self.robots, self.area_height, self.area_width) {
+                Some(steps) => Box::new(steps),
+                None => Box::new("no picture"),
+            },
+        )
+    }
+}
+
+// frames per row of a PGM or PPM strip
+const STRIP_COLUMNS: usize = 10;
+
+// parses `FROM..TO`
+fn parse_steps(s: &str) -> Option<Range<i32>> {
+    let (from, to) = s.split_once("..")?;
+    let steps = from.parse().ok()?..to.parse().ok()?;
+    (!steps.is_empty()).then_some(steps)
+}
+
+/// `frames <input> <output> [--steps FROM..TO]` draws the robots after each
+/// step in the range, which defaults to the steps up to the picture: as one
+/// animation for a `.gif`, or as a strip of frames for a `.pgm` or `.ppm`
+fn frames(args: &[String]) -> Result<(), AocError> {
+    let usage = || {
+        AocError::Usage(
+            "usage: aoc-2024 frames 14 <input> <output.pgm|.ppm|.gif> [--steps FROM..TO]".into(),
+        )
+    };
+    let (path, output, steps) = match args {
+        [path, output] => (path, output, None),
+        [path, output, flag, steps] if flag == "--steps" => (
+            path,
+            output,
+            Some(parse_steps(steps).ok_or(AocError::Usage(format!(
+                "invalid step range `{}`, expected FROM..TO",
+                steps
+            )))?),
+        ),
+        _ => return Err(usage()),
+    };
+    let path = Path::new(path);
+    let robots = Robots::parse(open_input(path)?).map_err(|e| e.in_file(path))?;
+    let (height, width) = (robots.area_height, robots.area_width);
+    let steps = match steps {
+        Some(steps) => steps,
+        None => {
+            let picture = picture_step(&robots.robots, height, width).unwrap_or(0);
+            (picture - STRIP_COLUMNS as i32 + 1).max(0)..picture + 1
+        }
+    };
+    let images = steps
+        .clone()
+        .map(|s| density(&robots.robots, s, height, width));
+
+    let output = Path::new(output);
+    let format = match output.extension().and_then(|e| e.to_str()) {
+        Some(format @ ("gif" | "pgm" | "ppm")) => format,
+        _ => return Err(usage()),
+    };
+    let mut out =
+        BufWriter::new(File::create(output).map_err(|e| AocError::from(e).in_file(output))?);
+    if format == "gif" {
+        // a tenth of a second a frame
+        let mut gif = GifWriter::new(out, width as usize, height as usize, 10)?;
+        for image in images {
+            gif.frame(&image)?;
+        }
+        gif.finish()?;
+    } else {
+        let strip = tile(&images.collect::<Vec<_>>(), STRIP_COLUMNS, 1, 64);
+        if format == "pgm" {
+            write_pgm(&strip, &mut out)?;
+        } else {
+            write_ppm(&strip, &mut out)?;
+        }
+        out.flush()?;
+    }
+    println!(
+        "wrote steps {}..{} to {}",
+        steps.start,
+        steps.end,
+        output.display()
+    );
+    Ok(())
+}
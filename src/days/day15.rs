@@ -0,0 +1,214 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::{
+    parse_chars, Answer, AocError, Day, Direction, FromChar, Grid, InputFileBuffer, Point, Solution,
+};
+
+pub const DAY: Day = Day::new::<Warehouse>(15);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Terrain {
+    Robot,
+    Wall,
+    Box,
+    // the two halves of a box in the widened warehouse
+    BoxLeft,
+    BoxRight,
+    Void,
+}
+
+impl FromChar for Terrain {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Void),
+            '@' => Some(Self::Robot),
+            '#' => Some(Self::Wall),
+            'O' => Some(Self::Box),
+            '[' => Some(Self::BoxLeft),
+            ']' => Some(Self::BoxRight),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Terrain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Wall => write!(f, "#"),
+            Self::Box => write!(f, "O"),
+            Self::BoxLeft => write!(f, "["),
+            Self::BoxRight => write!(f, "]"),
+            Self::Robot => write!(f, "@"),
+            Self::Void => write!(f, "."),
+        }
+    }
+}
+
+// attempts to move robot and returns new robot position
+fn move_robot(map: &mut Grid<Terrain>, position: Point, direction: &Direction) -> Point {
+    // find first void in robot path
+    // if no voids, no movement
+    let mut next = position;
+    let mut first_void = None;
+    let mut first_box = None;
+    loop {
+        next += *direction;
+        let Some(tile) = map.get(next) else {
+            break;
+        };
+        match tile {
+            Terrain::Wall => {
+                break;
+            }
+            Terrain::Void => {
+                first_void = Some(next);
+                break;
+            }
+            Terrain::Box if first_box.is_none() => first_box = Some(next),
+            _ => {}
+        }
+
+        if let (Some(_), Some(_)) = (first_void, first_box) {
+            break;
+        }
+    }
+
+    // find first box in robot path
+    // if no box then no worries
+    match (first_void, first_box) {
+        (Some(void), Some(b)) => {
+            map[position] = Terrain::Void;
+            map[b] = Terrain::Robot;
+            map[void] = Terrain::Box;
+            b
+        }
+        (Some(void), None) => {
+            map[position] = Terrain::Void;
+            map[void] = Terrain::Robot;
+            void
+        }
+        (None, _) => position,
+    }
+}
+
+// attempts to move robot in the widened warehouse and returns new robot
+// position. Pushing a box up or down also pushes every box overlapping either
+// of its halves, so the push fans out into a tree of boxes that must all be
+// free to move before anything moves
+fn move_wide_robot(map: &mut Grid<Terrain>, position: Point, direction: &Direction) -> Point {
+    let vertical = matches!(direction, Direction::Up | Direction::Down);
+
+    // every tile that will move, in the order they are reached
+    let mut moving = vec![position];
+    let mut seen = HashSet::from([position]);
+    let mut k = 0;
+    while k < moving.len() {
+        let next = moving[k] + *direction;
+        k += 1;
+
+        let pushed = match map.get(next) {
+            None | Some(Terrain::Wall) => return position,
+            Some(Terrain::Void) => vec![],
+            Some(Terrain::BoxLeft) if vertical => vec![next, next + Direction::Right],
+            Some(Terrain::BoxRight) if vertical => vec![next, next + Direction::Left],
+            Some(_) => vec![next],
+        };
+        for p in pushed {
+            if seen.insert(p) {
+                moving.push(p);
+            }
+        }
+    }
+
+    // tiles are reached in order of distance from the robot, so moving the
+    // farthest first never overwrites a tile that has yet to move
+    for p in moving.iter().rev() {
+        map[*p + *direction] = map[*p].clone();
+        map[*p] = Terrain::Void;
+    }
+
+    position + *direction
+}
+
+// doubles the width of the warehouse, turning each box into a `[]` pair
+fn widen(map: &Grid<Terrain>) -> Grid<Terrain> {
+    let mut wide = Grid::new(map.width() * 2, map.height(), Terrain::Void);
+    for (p, t) in map.iter() {
+        let (left, right) = match t {
+            Terrain::Wall => (Terrain::Wall, Terrain::Wall),
+            Terrain::Box => (Terrain::BoxLeft, Terrain::BoxRight),
+            Terrain::Robot => (Terrain::Robot, Terrain::Void),
+            t => (t.clone(), t.clone()),
+        };
+        let left_position = Point::new(p.i, p.j * 2);
+        wide[left_position] = left;
+        wide[left_position + Direction::Right] = right;
+    }
+    wide
+}
+
+fn simulate(
+    map: &mut Grid<Terrain>,
+    protocol: &[Direction],
+    move_robot: fn(&mut Grid<Terrain>, Point, &Direction) -> Point,
+) {
+    let Some(mut robot_position) = map.find(&Terrain::Robot) else {
+        return;
+    };
+
+    for direction in protocol {
+        robot_position = move_robot(map, robot_position, direction);
+    }
+}
+
+// boxes in the widened warehouse are measured from their left half
+fn score(map: &Grid<Terrain>) -> i32 {
+    map.positions(|t| *t == Terrain::Box || *t == Terrain::BoxLeft)
+        .map(|p| p.i * 100 + p.j)
+        .sum::<i32>()
+}
+
+#[allow(dead_code)]
+fn show_map(map: &Grid<Terrain>) {
+    println!("{}", map);
+    println!();
+}
+
+pub struct Warehouse {
+    map: Grid<Terrain>,
+    directions: Vec<Direction>,
+}
+
+impl Solution for Warehouse {
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        let map: Grid<Terrain> = Grid::parse(&mut input)?;
+        if map.find(&Terrain::Robot).is_none() {
+            return Err(AocError::expected(input.line(), "@"));
+        }
+
+        let mut directions: Vec<Direction> = vec![];
+        for l in input.numbered() {
+            let (line, l) = l?;
+            directions.extend(parse_chars(&l, line, |c| Direction::try_from(c).ok())?);
+        }
+
+        Ok(Self { map, directions })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut map = self.map.clone();
+        simulate(&mut map, &self.directions, move_robot);
+
+        Ok(Box::new(score(&map)))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut map = widen(&self.map);
+        simulate(&mut map, &self.directions, move_wide_robot);
+
+        Ok(Box::new(score(&map)))
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::{open_input, AocError};
+
+use super::{program_text, Instruction, Operation};
+
+// a line of source with its comment removed, split into an optional label and
+// an optional instruction
+struct SourceLine<'a> {
+    line: usize,
+    label: Option<&'a str>,
+    mnemonic: Option<&'a str>,
+    operand: Option<&'a str>,
+}
+
+fn split_line(line: usize, text: &str) -> Result<SourceLine<'_>, AocError> {
+    let text = text.split(';').next().unwrap_or_default().trim();
+    let (label, rest) = match text.split_once(':') {
+        Some((label, rest)) => (Some(label.trim()), rest.trim()),
+        None => (None, text),
+    };
+    if let Some(label) = label {
+        if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(AocError::parse(line, format!("invalid label `{}`", label)));
+        }
+    }
+
+    let mut words = rest.split_whitespace();
+    let mnemonic = words.next();
+    let operand = words.next();
+    if let Some(extra) = words.next() {
+        return Err(AocError::parse(line, format!("unexpected `{}`", extra)));
+    }
+
+    Ok(SourceLine {
+        line,
+        label,
+        mnemonic,
+        operand,
+    })
+}
+
+fn literal(operand: &str, line: usize) -> Result<i64, AocError> {
+    match operand.parse::<i64>() {
+        Ok(n) if (0..8).contains(&n) => Ok(n),
+        _ => Err(AocError::parse(
+            line,
+            format!("expected a literal from 0 to 7, found `{}`", operand),
+        )),
+    }
+}
+
+fn combo(operand: &str, line: usize) -> Result<i64, AocError> {
+    match operand {
+        "A" => Ok(4),
+        "B" => Ok(5),
+        "C" => Ok(6),
+        _ => match operand.parse::<i64>() {
+            Ok(n) if (0..4).contains(&n) => Ok(n),
+            _ => Err(AocError::parse(
+                line,
+                format!(
+                    "expected A, B, C or a literal from 0 to 3, found `{}`",
+                    operand
+                ),
+            )),
+        },
+    }
+}
+
+fn jump(operand: &str, labels: &HashMap<&str, i64>, line: usize) -> Result<i64, AocError> {
+    if operand.starts_with(|c: char| c.is_ascii_digit()) {
+        return literal(operand, line);
+    }
+    match labels.get(operand) {
+        Some(&address) if address < 8 => Ok(address),
+        Some(address) => Err(AocError::parse(
+            line,
+            format!(
+                "label `{}` is at address {}, beyond the reach of jnz",
+                operand, address
+            ),
+        )),
+        None => Err(AocError::parse(
+            line,
+            format!("undefined label `{}`", operand),
+        )),
+    }
+}
+
+/// assembles mnemonic source, such as the output of the disassembler, into
+/// instructions. `;` starts a comment and `name:` labels the next instruction
+pub(super) fn assemble(source: &str) -> Result<Vec<Instruction>, AocError> {
+    let lines = source
+        .lines()
+        .enumerate()
+        .map(|(i, l)| split_line(i + 1, l))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // first pass: give each label the address of the instruction it precedes
+    let mut labels = HashMap::new();
+    let mut address = 0;
+    for l in &lines {
+        if let Some(label) = l.label {
+            if labels.insert(label, address).is_some() {
+                return Err(AocError::parse(
+                    l.line,
+                    format!("label `{}` is defined twice", label),
+                ));
+            }
+        }
+        if l.mnemonic.is_some() {
+            address += 2;
+        }
+    }
+
+    // second pass: encode each instruction
+    let mut instructions = vec![];
+    for l in &lines {
+        let Some(mnemonic) = l.mnemonic else {
+            continue;
+        };
+        let operator = Operation::from_mnemonic(mnemonic).ok_or(AocError::parse(
+            l.line,
+            format!("unknown mnemonic `{}`", mnemonic),
+        ))?;
+        let operand = match (operator, l.operand) {
+            (Operation::Bxc, None) => 0,
+            (_, None) => return Err(AocError::parse(l.line, "missing operand")),
+            (Operation::Bxl | Operation::Bxc, Some(operand)) => literal(operand, l.line)?,
+            (Operation::Jnz, Some(operand)) => jump(operand, &labels, l.line)?,
+            (_, Some(operand)) => combo(operand, l.line)?,
+        };
+        instructions.push(Instruction { operator, operand });
+    }
+
+    Ok(instructions)
+}
+
+/// `asm <source>`
+pub(super) fn run(args: &[String]) -> Result<(), AocError> {
+    let [path] = args else {
+        return Err(AocError::Usage("usage: aoc-2024 asm 17 <source>".into()));
+    };
+    let path = Path::new(path);
+    let source = open_input(path)?
+        .collect::<Result<Vec<String>, _>>()?
+        .join("\n");
+    let instructions = assemble(&source).map_err(|e| e.in_file(path))?;
+
+    println!("{}", program_text(&instructions));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{disasm::disassemble, parse_instructions};
+    use super::*;
+
+    #[test]
+    fn round_trips_with_disassembler() {
+        let text = "Program: 2,4,1,2,7,5,0,3,1,7,4,1,5,5,3,0";
+        let instructions = parse_instructions(text.strip_prefix("Program:").unwrap(), 1).unwrap();
+
+        for pseudo in [false, true] {
+            let source = disassemble(&instructions, pseudo);
+            assert_eq!(program_text(&assemble(&source).unwrap()), text);
+        }
+    }
+
+    #[test]
+    fn reports_offending_line() {
+        let err = assemble("start:\n    adv 3\n    jnz end\n").unwrap_err();
+        assert_eq!(err.to_string(), "3: undefined label `end`");
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use crate::{open_input, AocError};
+
+use super::{
+    disasm::render, parse_input, BigUint, Machine, RegisterBlock, System, VmError, Word,
+    CYCLE_LIMIT,
+};
+
+const HELP: &str = "commands:
+  s, step [n]       execute n instructions (default 1), tracing each
+  c, continue       run until a breakpoint, the program halts or the cycle limit
+  b, break ip <n>   stop before executing the instruction at address n
+  b, break out <n>  stop once the output holds n values
+  d, delete         remove all breakpoints
+  r, regs           show the registers and output
+  q, quit           stop debugging";
+
+impl<W: Display> Display for RegisterBlock<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.reg_a, self.reg_b, self.reg_c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Breakpoint {
+    Address(i64),
+    OutputLength(usize),
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "ip {}", address),
+            Self::OutputLength(n) => write!(f, "output length {}", n),
+        }
+    }
+}
+
+/// Why execution stopped
+enum Stop {
+    Halted,
+    Breakpoint(Breakpoint),
+    CycleLimit,
+    Error(VmError),
+}
+
+struct Debugger<W> {
+    system: System<W>,
+    breakpoints: Vec<Breakpoint>,
+    cycles: usize,
+    limit: usize,
+}
+
+impl<W: Word> Debugger<W> {
+    fn new(system: System<W>, limit: usize) -> Self {
+        Self {
+            system,
+            breakpoints: vec![],
+            cycles: 0,
+            limit,
+        }
+    }
+
+    // executes one instruction and describes it, or explains why it could not
+    fn step(&mut self) -> Result<String, Stop> {
+        let Some(instruction) = self.system.current() else {
+            return Err(Stop::Halted);
+        };
+        if self.cycles >= self.limit {
+            return Err(Stop::CycleLimit);
+        }
+        let instruction = render(instruction);
+        let address = self.system.address();
+        let before = self.system.registers.clone();
+
+        self.system.step().map_err(Stop::Error)?;
+        self.cycles += 1;
+
+        Ok(format!(
+            "{:>6}  ip={:<3} {:<8} {} -> {}  out=[{}]",
+            self.cycles,
+            address,
+            instruction,
+            before,
+            self.system.registers,
+            self.system.output_text()
+        ))
+    }
+
+    // the breakpoint, if any, hit by the last step, given the output length
+    // before it
+    fn breakpoint(&self, output_before: usize) -> Option<Breakpoint> {
+        self.breakpoints.iter().copied().find(|b| match *b {
+            Breakpoint::Address(address) => {
+                !self.system.halted() && self.system.address() == address
+            }
+            Breakpoint::OutputLength(n) => output_before < n && self.system.output.len() >= n,
+        })
+    }
+
+    // runs until stopped, always executing at least one instruction so that a
+    // breakpoint can be continued past
+    fn resume(&mut self, trace: bool) -> Stop {
+        loop {
+            let output_before = self.system.output.len();
+            match self.step() {
+                Ok(line) if trace => println!("{}", line),
+                Ok(_) => {}
+                Err(stop) => return stop,
+            }
+            if let Some(b) = self.breakpoint(output_before) {
+                return Stop::Breakpoint(b);
+            }
+        }
+    }
+
+    fn report(&self, stop: &Stop) {
+        match stop {
+            Stop::Halted => println!("halted after {} cycles", self.cycles),
+            Stop::Breakpoint(b) => println!("stopped at breakpoint on {}", b),
+            Stop::CycleLimit => println!("aborted: cycle limit of {} reached", self.limit),
+            Stop::Error(e) => println!("error: {}", e),
+        }
+        self.show();
+    }
+
+    fn show(&self) {
+        let next = match self.system.current() {
+            Some(instruction) => render(instruction),
+            None => "halted".to_string(),
+        };
+        println!(
+            "ip={} next: {}  {}  out=[{}]",
+            self.system.address(),
+            next,
+            self.system.registers,
+            self.system.output_text()
+        );
+    }
+
+    // handles one REPL command, returning false to quit
+    fn command(&mut self, line: &str) -> Result<bool, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..] {
+            [] => {}
+            ["s" | "step"] | ["s" | "step", _] => {
+                let n = match words.get(1) {
+                    Some(n) => n.parse().map_err(|_| format!("invalid count `{}`", n))?,
+                    None => 1,
+                };
+                for _ in 0..n {
+                    match self.step() {
+                        Ok(line) => println!("{}", line),
+                        Err(stop) => {
+                            self.report(&stop);
+                            break;
+                        }
+                    }
+                }
+            }
+            ["c" | "continue"] => {
+                let stop = self.resume(false);
+                self.report(&stop);
+            }
+            ["b" | "break", kind, n] => {
+                let breakpoint = match kind {
+                    "ip" => n.parse().ok().map(Breakpoint::Address),
+                    "out" => n.parse().ok().map(Breakpoint::OutputLength),
+                    _ => return Err(format!("unknown breakpoint `{}`", kind)),
+                }
+                .ok_or(format!("invalid number `{}`", n))?;
+                self.breakpoints.push(breakpoint);
+                println!("breakpoint on {}", breakpoint);
+            }
+            ["d" | "delete"] => self.breakpoints.clear(),
+            ["r" | "regs"] => self.show(),
+            ["q" | "quit"] => return Ok(false),
+            ["h" | "help"] => println!("{}", HELP),
+            _ => return Err(format!("unknown command `{}`, try `help`", line.trim())),
+        }
+        Ok(true)
+    }
+}
+
+/// The options shared by `trace` and `debug`
+struct Options<'a> {
+    path: &'a Path,
+    limit: usize,
+    /// the register width given with `--width`, if any
+    width: Option<Width>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Width {
+    Bits64,
+    Bits128,
+    Unbounded,
+}
+
+// splits `<input> [--limit N] [--width 64|128|big]` from the arguments of a
+// mode
+fn options<'a>(args: &'a [String], usage: &str) -> Result<Options<'a>, AocError> {
+    let Some((path, mut flags)) = args.split_first() else {
+        return Err(AocError::Usage(usage.into()));
+    };
+    let mut options = Options {
+        path: Path::new(path),
+        limit: CYCLE_LIMIT,
+        width: None,
+    };
+    while let [flag, value, rest @ ..] = flags {
+        match (flag.as_str(), value.as_str()) {
+            ("--limit", _) => {
+                options.limit = value
+                    .parse()
+                    .map_err(|_| AocError::Usage(format!("invalid cycle limit `{}`", value)))?
+            }
+            ("--width", "64") => options.width = Some(Width::Bits64),
+            ("--width", "128") => options.width = Some(Width::Bits128),
+            ("--width", "big") => options.width = Some(Width::Unbounded),
+            ("--width", _) => {
+                return Err(AocError::Usage(format!(
+                    "invalid width `{}`, expected 64, 128 or big",
+                    value
+                )))
+            }
+            _ => return Err(AocError::Usage(usage.into())),
+        }
+        flags = rest;
+    }
+    if !flags.is_empty() {
+        return Err(AocError::Usage(usage.into()));
+    }
+    Ok(options)
+}
+
+// the width given with `--width`, or else the narrowest that holds the
+// input, as `run` picks it
+fn width(options: &Options) -> Result<Width, AocError> {
+    if let Some(width) = options.width {
+        return Ok(width);
+    }
+    let path = options.path;
+    let system = parse_input(open_input(path)?).map_err(|e| e.in_file(path))?;
+    Ok(match Machine::new(system) {
+        Machine::Bits64(_) => Width::Bits64,
+        Machine::Bits128(_) => Width::Bits128,
+        Machine::Unbounded(_) => Width::Unbounded,
+    })
+}
+
+fn load<W: Word>(options: &Options) -> Result<Debugger<W>, AocError> {
+    let path = options.path;
+    let system = parse_input(open_input(path)?).map_err(|e| e.in_file(path))?;
+    Ok(Debugger::new(system, options.limit))
+}
+
+/// `trace <input> [--limit N] [--width 64|128|big]`
+pub(super) fn trace(args: &[String]) -> Result<(), AocError> {
+    let options = options(
+        args,
+        "usage: aoc-2024 trace 17 <input> [--limit N] [--width 64|128|big]",
+    )?;
+    let stop = match width(&options)? {
+        Width::Bits64 => run_trace(load::<u64>(&options)?),
+        Width::Bits128 => run_trace(load::<u128>(&options)?),
+        Width::Unbounded => run_trace(load::<BigUint>(&options)?),
+    };
+    // an aborted run fails, so that scripts can tell it from a halt
+    match stop {
+        Stop::Halted | Stop::Breakpoint(_) => Ok(()),
+        Stop::CycleLimit => Err(VmError::CycleLimit {
+            cycles: options.limit,
+        }
+        .into()),
+        Stop::Error(e) => Err(e.into()),
+    }
+}
+
+fn run_trace<W: Word>(mut debugger: Debugger<W>) -> Stop {
+    let stop = debugger.resume(true);
+    debugger.report(&stop);
+    stop
+}
+
+/// `debug <input> [--limit N] [--width 64|128|big]`, an interactive debugger
+/// reading commands from stdin
+pub(super) fn debug(args: &[String]) -> Result<(), AocError> {
+    let options = options(
+        args,
+        "usage: aoc-2024 debug 17 <input> [--limit N] [--width 64|128|big]",
+    )?;
+    match width(&options)? {
+        Width::Bits64 => interact(load::<u64>(&options)?),
+        Width::Bits128 => interact(load::<u128>(&options)?),
+        Width::Unbounded => interact(load::<BigUint>(&options)?),
+    }
+}
+
+fn interact<W: Word>(mut debugger: Debugger<W>) -> Result<(), AocError> {
+    debugger.show();
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            break;
+        }
+        match debugger.command(&line) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => println!("{}", e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::{load, registers, TempInput};
+    use super::*;
+
+    #[test]
+    fn halting_on_the_last_allowed_cycle_is_not_aborted() {
+        let system = load::<u64>(&(registers("1") + "Program: 0,3,5,4,3,0"));
+        let mut debugger = Debugger::new(system.unwrap(), 3);
+        assert!(matches!(debugger.resume(false), Stop::Halted));
+        assert_eq!(debugger.cycles, 3);
+
+        let system = load::<u64>(&(registers("8") + "Program: 0,3,5,4,3,0"));
+        let mut debugger = Debugger::new(system.unwrap(), 3);
+        assert!(matches!(debugger.resume(false), Stop::CycleLimit));
+    }
+    #[test]
+    fn aborted_traces_fail() {
+        let looping = TempInput::new(&(registers("5") + "Program: 3,0"));
+        let args = |limit: &str| {
+            vec![
+                looping.path().display().to_string(),
+                "--limit".to_string(),
+                limit.to_string(),
+            ]
+        };
+        let err = trace(&args("5")).unwrap_err();
+        assert_eq!(err.to_string(), "no halt after 5 cycles");
+
+        let halting = TempInput::new(&(registers("1") + "Program: 0,3,5,4,3,0"));
+        assert!(trace(&[halting.path().display().to_string()]).is_ok());
+    }
+    #[test]
+    fn picks_a_width_that_holds_the_input() {
+        let input = TempInput::new(&(registers("1180591620717411303424") + "Program: 0,3,5,4,3,0"));
+        let args = |flags: &[&str]| {
+            let mut args = vec![input.path().display().to_string()];
+            args.extend(flags.iter().map(|f| f.to_string()));
+            args
+        };
+        let width = |flags: &[&str]| width(&options(&args(flags), "usage").unwrap());
+
+        assert!(matches!(width(&[]), Ok(Width::Bits128)));
+        assert!(matches!(width(&["--width", "big"]), Ok(Width::Unbounded)));
+        assert!(trace(&args(&[])).is_ok());
+        assert_eq!(
+            trace(&args(&["--width", "64"])).unwrap_err().to_string(),
+            format!(
+                "{}:1: register A does not fit in 64-bit registers, found 1180591620717411303424",
+                input.path().display()
+            )
+        );
+    }
+}
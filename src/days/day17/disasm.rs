@@ -0,0 +1,126 @@
+use std::{collections::BTreeSet, fmt::Display, path::Path};
+
+use crate::{open_input, AocError};
+
+use super::{parse_input, BigUint, Instruction, Operation, RegisterBlock, System};
+
+/// a combo operand as written in assembly
+pub(super) fn combo_name(operand: i64) -> String {
+    match operand {
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        n => n.to_string(),
+    }
+}
+
+pub(super) fn label(address: i64) -> String {
+    format!("L{}", address)
+}
+
+// addresses that are jumped to and land on an instruction
+fn jump_targets(instructions: &[Instruction]) -> BTreeSet<i64> {
+    instructions
+        .iter()
+        .filter(|i| matches!(i.operator, Operation::Jnz))
+        .map(|i| i.operand)
+        .filter(|&a| a % 2 == 0 && a >= 0 && ((a / 2) as usize) < instructions.len())
+        .collect()
+}
+
+fn jump_target(operand: i64, labels: &BTreeSet<i64>) -> String {
+    if labels.contains(&operand) {
+        label(operand)
+    } else {
+        operand.to_string()
+    }
+}
+
+// the instruction's operand, or `None` for `bxc`, which ignores a zero operand
+fn operand(instruction: &Instruction, labels: &BTreeSet<i64>) -> Option<String> {
+    match instruction.operator {
+        Operation::Adv | Operation::Bst | Operation::Out | Operation::Bdv | Operation::Cdv => {
+            Some(combo_name(instruction.operand))
+        }
+        Operation::Bxl => Some(instruction.operand.to_string()),
+        Operation::Jnz => Some(jump_target(instruction.operand, labels)),
+        // kept when set so the program round-trips exactly
+        Operation::Bxc if instruction.operand != 0 => Some(instruction.operand.to_string()),
+        Operation::Bxc => None,
+    }
+}
+
+/// a single instruction as assembly, with jump targets as plain addresses
+pub(super) fn render(instruction: &Instruction) -> String {
+    match operand(instruction, &BTreeSet::new()) {
+        Some(operand) => format!("{} {}", instruction.operator.mnemonic(), operand),
+        None => instruction.operator.mnemonic().to_string(),
+    }
+}
+
+fn pseudo_code(instruction: &Instruction, labels: &BTreeSet<i64>) -> String {
+    let combo = combo_name(instruction.operand);
+    match instruction.operator {
+        Operation::Adv => format!("A = A >> {}", combo),
+        Operation::Bxl => format!("B = B ^ {}", instruction.operand),
+        Operation::Bst => format!("B = {} % 8", combo),
+        Operation::Jnz => format!(
+            "if A != 0 goto {}",
+            jump_target(instruction.operand, labels)
+        ),
+        Operation::Bxc => "B = B ^ C".to_string(),
+        Operation::Out => format!("out({} % 8)", combo),
+        Operation::Bdv => format!("B = A >> {}", combo),
+        Operation::Cdv => format!("C = A >> {}", combo),
+    }
+}
+
+/// renders the program as assembly, one instruction per line, with every jump
+/// target labelled and optionally each instruction's effect as a comment
+pub(super) fn disassemble(instructions: &[Instruction], pseudo: bool) -> String {
+    let labels = jump_targets(instructions);
+
+    let mut lines = vec![];
+    for (ip, instruction) in instructions.iter().enumerate() {
+        let address = ip as i64 * 2;
+        if labels.contains(&address) {
+            lines.push(format!("{}:", label(address)));
+        }
+        let mut line = match operand(instruction, &labels) {
+            Some(operand) => format!("    {} {}", instruction.operator.mnemonic(), operand),
+            None => format!("    {}", instruction.operator.mnemonic()),
+        };
+        if pseudo {
+            line = format!("{:<14}; {}", line, pseudo_code(instruction, &labels));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn registers_comment<W: Display>(registers: &RegisterBlock<W>) -> String {
+    format!(
+        "; A = {}, B = {}, C = {}",
+        registers.reg_a, registers.reg_b, registers.reg_c
+    )
+}
+
+/// `disasm <input> [--pseudo]`
+pub(super) fn run(args: &[String]) -> Result<(), AocError> {
+    let (path, pseudo) = match args {
+        [path] => (path, false),
+        [path, flag] if flag == "--pseudo" => (path, true),
+        _ => {
+            return Err(AocError::Usage(
+                "usage: aoc-2024 disasm 17 <input> [--pseudo]".into(),
+            ))
+        }
+    };
+    let path = Path::new(path);
+    // registers of any size can be listed
+    let system: System<BigUint> = parse_input(open_input(path)?).map_err(|e| e.in_file(path))?;
+
+    println!("{}", registers_comment(&system.registers));
+    println!("{}", disassemble(&system.instructions, pseudo));
+    Ok(())
+}
@@ -0,0 +1,600 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Answer, AocError, Day, InputFileBuffer, Mode, Solution};
+
+use word::{BigUint, Word};
+
+mod asm;
+mod debug;
+mod disasm;
+mod symbolic;
+#[cfg(test)]
+mod testing;
+mod word;
+
+pub const DAY: Day = Day::new::<Machine>(17).with_modes(&[
+    Mode {
+        name: "disasm",
+        usage: "<input> [--pseudo]",
+        run: disasm::run,
+    },
+    Mode {
+        name: "asm",
+        usage: "<source>",
+        run: asm::run,
+    },
+    Mode {
+        name: "trace",
+        usage: "<input> [--limit N] [--width 64|128|big]",
+        run: debug::trace,
+    },
+    Mode {
+        name: "debug",
+        usage: "<input> [--limit N] [--width 64|128|big]",
+        run: debug::debug,
+    },
+    Mode {
+        name: "symbolic",
+        usage: "<input> [target]",
+        run: symbolic::run,
+    },
+]);
+
+/// steps executed before a run is assumed to loop forever
+const CYCLE_LIMIT: usize = 1_000_000;
+
+const PROGRAM_PREFIX: &str = "Program:";
+
+/// An error raised while running a program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    /// the instruction at `address` used combo operand 7
+    ReservedOperand { address: i64 },
+    /// the program ran for `cycles` steps without halting
+    CycleLimit { cycles: usize },
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReservedOperand { address } => {
+                write!(f, "reserved combo operand 7 at address {}", address)
+            }
+            Self::CycleLimit { cycles } => write!(f, "no halt after {} cycles", cycles),
+        }
+    }
+}
+
+impl From<VmError> for AocError {
+    fn from(e: VmError) -> Self {
+        Self::Runtime(e.to_string())
+    }
+}
+
+/// A value of A too large for the register width, met while searching
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Overflow;
+
+#[derive(Debug, Clone)]
+struct RegisterBlock<W> {
+    reg_a: W,
+    reg_b: W,
+    reg_c: W,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Adv = 0,
+    Bxl = 1,
+    Bst = 2,
+    Jnz = 3,
+    Bxc = 4,
+    Out = 5,
+    Bdv = 6,
+    Cdv = 7,
+}
+
+impl TryFrom<i64> for Operation {
+    type Error = i64;
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            x if x == Operation::Adv as i64 => Ok(Operation::Adv),
+            x if x == Operation::Bxl as i64 => Ok(Operation::Bxl),
+            x if x == Operation::Bst as i64 => Ok(Operation::Bst),
+            x if x == Operation::Jnz as i64 => Ok(Operation::Jnz),
+            x if x == Operation::Bxc as i64 => Ok(Operation::Bxc),
+            x if x == Operation::Out as i64 => Ok(Operation::Out),
+            x if x == Operation::Bdv as i64 => Ok(Operation::Bdv),
+            x if x == Operation::Cdv as i64 => Ok(Operation::Cdv),
+            x => Err(x),
+        }
+    }
+}
+
+impl Operation {
+    /// whether the operand is a combo operand rather than a literal
+    fn takes_combo(&self) -> bool {
+        matches!(
+            self,
+            Operation::Adv | Operation::Bst | Operation::Out | Operation::Bdv | Operation::Cdv
+        )
+    }
+
+    fn mnemonic(&self) -> &'static str {
+        match self {
+            Operation::Adv => "adv",
+            Operation::Bxl => "bxl",
+            Operation::Bst => "bst",
+            Operation::Jnz => "jnz",
+            Operation::Bxc => "bxc",
+            Operation::Out => "out",
+            Operation::Bdv => "bdv",
+            Operation::Cdv => "cdv",
+        }
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        match mnemonic {
+            "adv" => Some(Operation::Adv),
+            "bxl" => Some(Operation::Bxl),
+            "bst" => Some(Operation::Bst),
+            "jnz" => Some(Operation::Jnz),
+            "bxc" => Some(Operation::Bxc),
+            "out" => Some(Operation::Out),
+            "bdv" => Some(Operation::Bdv),
+            "cdv" => Some(Operation::Cdv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    operator: Operation,
+    operand: i64,
+}
+
+impl Instruction {
+    /// executes the instruction at `ip` and returns how far to move the
+    /// instruction pointer
+    pub fn process<W: Word>(
+        &self,
+        registers: &mut RegisterBlock<W>,
+        out: &mut Vec<i64>,
+        ip: i64,
+    ) -> Result<i64, VmError> {
+        match self.operator {
+            Operation::Adv => self.adv(registers),
+            Operation::Bxl => self.bxl(registers),
+            Operation::Bst => self.bst(registers),
+            Operation::Jnz => self.jnz(registers, ip),
+            Operation::Bxc => self.bxc(registers),
+            Operation::Out => self.out(registers, out),
+            Operation::Bdv => self.bdv(registers),
+            Operation::Cdv => self.cdv(registers),
+        }
+        .ok_or(VmError::ReservedOperand { address: ip * 2 })
+    }
+
+    // the combo operand's value, or `None` for the reserved operand 7
+    fn combo<W: Word>(&self, registers: &RegisterBlock<W>) -> Option<W> {
+        match self.operand {
+            0..=3 => Some(W::from_u64(self.operand as u64)),
+            4 => Some(registers.reg_a.clone()),
+            5 => Some(registers.reg_b.clone()),
+            6 => Some(registers.reg_c.clone()),
+            _ => None,
+        }
+    }
+
+    fn adv<W: Word>(&self, registers: &mut RegisterBlock<W>) -> Option<i64> {
+        registers.reg_a = registers.reg_a.shr(&self.combo(registers)?);
+        Some(1)
+    }
+
+    fn bxl<W: Word>(&self, registers: &mut RegisterBlock<W>) -> Option<i64> {
+        registers.reg_b = registers.reg_b.xor(&W::from_u64(self.operand as u64));
+        Some(1)
+    }
+
+    fn bst<W: Word>(&self, registers: &mut RegisterBlock<W>) -> Option<i64> {
+        registers.reg_b = W::from_u64(self.combo(registers)?.low_bits());
+        Some(1)
+    }
+
+    fn jnz<W: Word>(&self, registers: &RegisterBlock<W>, ip: i64) -> Option<i64> {
+        if registers.reg_a.is_zero() {
+            Some(1)
+        } else {
+            Some((self.operand - ip * 2) / 2)
+        }
+    }
+
+    fn bxc<W: Word>(&self, registers: &mut RegisterBlock<W>) -> Option<i64> {
+        registers.reg_b = registers.reg_b.xor(&registers.reg_c);
+        Some(1)
+    }
+
+    fn out<W: Word>(&self, registers: &RegisterBlock<W>, out: &mut Vec<i64>) -> Option<i64> {
+        out.push(self.combo(registers)?.low_bits() as i64);
+        Some(1)
+    }
+
+    fn bdv<W: Word>(&self, registers: &mut RegisterBlock<W>) -> Option<i64> {
+        registers.reg_b = registers.reg_a.shr(&self.combo(registers)?);
+        Some(1)
+    }
+
+    fn cdv<W: Word>(&self, registers: &mut RegisterBlock<W>) -> Option<i64> {
+        registers.reg_c = registers.reg_a.shr(&self.combo(registers)?);
+        Some(1)
+    }
+}
+
+/// The machine, with registers `W` wide
+#[derive(Debug, Clone)]
+pub struct System<W = u64> {
+    registers: RegisterBlock<W>,
+    output: Vec<i64>,
+    operations_pointer: i64,
+    instructions: Vec<Instruction>,
+}
+
+impl<W: Word> System<W> {
+    fn with_reg_a(&self, reg_a: W) -> Self {
+        let mut sys = self.clone();
+        sys.registers.reg_a = reg_a;
+        sys
+    }
+
+    /// the same machine with registers of another width, or `None` if they
+    /// cannot hold the current values
+    fn convert<V: Word>(&self) -> Option<System<V>> {
+        let convert = |w: &W| w.to_string().parse().ok();
+        Some(System {
+            registers: RegisterBlock {
+                reg_a: convert(&self.registers.reg_a)?,
+                reg_b: convert(&self.registers.reg_b)?,
+                reg_c: convert(&self.registers.reg_c)?,
+            },
+            output: self.output.clone(),
+            operations_pointer: self.operations_pointer,
+            instructions: self.instructions.clone(),
+        })
+    }
+
+    /// the same machine with wider registers
+    fn widen<V: Word>(&self) -> System<V> {
+        self.convert()
+            .expect("a wider register holds every narrower value")
+    }
+
+    /// the program as it appears in the input
+    fn program(&self) -> Vec<i64> {
+        encode(&self.instructions)
+    }
+
+    fn halted(&self) -> bool {
+        self.operations_pointer < 0 || self.operations_pointer as usize >= self.instructions.len()
+    }
+
+    /// the instruction pointer as the puzzle counts it, in 3-bit words
+    fn address(&self) -> i64 {
+        self.operations_pointer * 2
+    }
+
+    /// the instruction about to execute, if the program has not halted
+    fn current(&self) -> Option<&Instruction> {
+        if self.halted() {
+            None
+        } else {
+            Some(&self.instructions[self.operations_pointer as usize])
+        }
+    }
+
+    // executes the instruction at the instruction pointer, which must exist
+    fn step(&mut self) -> Result<(), VmError> {
+        let point_increment = self.instructions[self.operations_pointer as usize].process(
+            &mut self.registers,
+            &mut self.output,
+            self.operations_pointer,
+        )?;
+        self.operations_pointer += point_increment;
+        Ok(())
+    }
+
+    // runs until the program halts, or until it has output more than
+    // `max_output` values
+    fn execute(&mut self, max_output: usize) -> Result<(), VmError> {
+        let mut cycles = 0;
+        while !self.halted() && self.output.len() <= max_output {
+            if cycles == CYCLE_LIMIT {
+                return Err(VmError::CycleLimit { cycles });
+            }
+            self.step()?;
+            cycles += 1;
+        }
+        Ok(())
+    }
+
+    fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .map(|i| i.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    fn run(&mut self) -> Result<String, VmError> {
+        self.execute(usize::MAX)?;
+
+        Ok(self.output_text())
+    }
+}
+
+// finds the lowest A whose output is `program[k..]`, given the octal digits of A
+// that produced `program[k + 1..]`. Each pass through the program shifts A
+// right by 3 bits, so every output value is decided by one more octal digit
+// of A, working from the end of the program back to the start
+fn search_quine<W: Word>(
+    system: &System<W>,
+    program: &[i64],
+    k: usize,
+    a: &W,
+) -> Result<Option<W>, Overflow> {
+    for digit in 0..8 {
+        let candidate = a.push_bits(3, digit).ok_or(Overflow)?;
+        let mut sys = system.with_reg_a(candidate.clone());
+        if sys.execute(program.len() - k).is_err() || sys.output != program[k..] {
+            continue;
+        }
+        if k == 0 {
+            return Ok(Some(candidate));
+        }
+        if let Some(found) = search_quine(system, program, k - 1, &candidate)? {
+            return Ok(Some(found));
+        }
+    }
+    Ok(None)
+}
+
+/// the lowest initial value of register A that makes the program output
+/// itself, or `Overflow` if the search needs wider registers
+fn quine<W: Word>(system: &System<W>) -> Result<Option<W>, Overflow> {
+    let program = system.program();
+    let Some(k) = program.len().checked_sub(1) else {
+        return Ok(None);
+    };
+    let Some(a) = search_quine(system, &program, k, &W::from_u64(0))? else {
+        return Ok(None);
+    };
+
+    // check the whole run once more with the interpreter
+    let mut sys = system.with_reg_a(a.clone());
+    Ok((sys.run().is_ok() && sys.output == program).then_some(a))
+}
+
+fn boxed<W: Word>(a: Option<W>) -> Option<Answer> {
+    a.map(|a| Box::new(a) as Answer)
+}
+
+/// `quine`, retrying with arbitrary-precision registers if A does not fit
+fn widening_quine(system: &System<u128>) -> Option<Answer> {
+    quine(system)
+        .map(boxed)
+        .or_else(|_| quine(&system.widen::<BigUint>()).map(boxed))
+        .expect("arbitrary-precision registers never overflow")
+}
+
+/// A machine with the narrowest registers that hold its initial values
+pub enum Machine {
+    Bits64(System<u64>),
+    Bits128(System<u128>),
+    Unbounded(System<BigUint>),
+}
+
+impl Machine {
+    fn new(system: System<BigUint>) -> Self {
+        match (system.convert(), system.convert()) {
+            (Some(system), _) => Self::Bits64(system),
+            (None, Some(system)) => Self::Bits128(system),
+            (None, None) => Self::Unbounded(system),
+        }
+    }
+}
+
+fn encode(instructions: &[Instruction]) -> Vec<i64> {
+    instructions
+        .iter()
+        .flat_map(|i| [i.operator as i64, i.operand])
+        .collect()
+}
+
+/// the `Program:` line of an input file running `instructions`
+fn program_text(instructions: &[Instruction]) -> String {
+    let values: Vec<String> = encode(instructions).iter().map(|v| v.to_string()).collect();
+    format!("{} {}", PROGRAM_PREFIX, values.join(","))
+}
+
+/// parses the comma-separated values following `Program:`, reporting the
+/// column of any value that does not make a valid instruction
+fn parse_instructions(program: &str, line: usize) -> Result<Vec<Instruction>, AocError> {
+    let mut values = vec![];
+    let mut offset = PROGRAM_PREFIX.len();
+    for s in program.split(',') {
+        let column = offset + s.len() - s.trim_start().len() + 1;
+        offset += s.len() + 1;
+        let value = match s.trim().parse::<i64>() {
+            Ok(v) if (0..8).contains(&v) => v,
+            Ok(_) => {
+                return Err(AocError::parse_at(
+                    line,
+                    column,
+                    format!("`{}` is not a 3-bit value", s.trim()),
+                ))
+            }
+            Err(_) => {
+                return Err(AocError::parse_at(
+                    line,
+                    column,
+                    format!("invalid number `{}`", s.trim()),
+                ))
+            }
+        };
+        values.push((column, value));
+    }
+
+    if values.len() % 2 == 1 {
+        let (column, opcode) = values[values.len() - 1];
+        return Err(AocError::parse_at(
+            line,
+            column,
+            format!("opcode {} has no operand", opcode),
+        ));
+    }
+
+    let mut instructions = vec![];
+    for pair in values.chunks(2) {
+        let [(_, opcode), (column, operand)] = pair else {
+            unreachable!()
+        };
+        let operator = Operation::try_from(*opcode).expect("opcodes are 3-bit values");
+        if operator.takes_combo() && *operand == 7 {
+            return Err(AocError::parse_at(
+                line,
+                *column,
+                format!("combo operand 7 is reserved ({} 7)", operator.mnemonic()),
+            ));
+        }
+        instructions.push(Instruction {
+            operator,
+            operand: *operand,
+        });
+    }
+    Ok(instructions)
+}
+
+fn parse_register<W: Word>(input: &mut InputFileBuffer, name: &str) -> Result<W, AocError> {
+    let field = input.next_field(&format!("Register {}:", name))?;
+    let field = field.trim();
+    match field.parse::<W>() {
+        Ok(value) => Ok(value),
+        Err(_) if field.starts_with('-') => Err(AocError::parse(
+            input.line(),
+            format!("register {} must not be negative, found {}", name, field),
+        )),
+        Err(_) if !field.is_empty() && field.chars().all(|c| c.is_ascii_digit()) => {
+            Err(AocError::parse(
+                input.line(),
+                format!(
+                    "register {} does not fit in {} registers, found {}",
+                    name,
+                    W::WIDTH,
+                    field
+                ),
+            ))
+        }
+        Err(_) => Err(AocError::parse(
+            input.line(),
+            format!("invalid number `{}`", field),
+        )),
+    }
+}
+
+fn parse_input<W: Word>(mut input: InputFileBuffer) -> Result<System<W>, AocError> {
+    let reg_a = parse_register(&mut input, "A")?;
+    let reg_b = parse_register(&mut input, "B")?;
+    let reg_c = parse_register(&mut input, "C")?;
+    input.skip_blank()?;
+    let instructions = parse_instructions(&input.next_field(PROGRAM_PREFIX)?, input.line())?;
+
+    Ok(System {
+        registers: RegisterBlock {
+            reg_a,
+            reg_b,
+            reg_c,
+        },
+        output: vec![],
+        operations_pointer: 0,
+        instructions,
+    })
+}
+
+impl Solution for Machine {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        Ok(Self::new(parse_input(input)?))
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let output = match self {
+            Self::Bits64(system) => system.clone().run(),
+            Self::Bits128(system) => system.clone().run(),
+            Self::Unbounded(system) => system.clone().run(),
+        };
+        Ok(Box::new(output?))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let a = match self {
+            Self::Bits64(system) => quine(system)
+                .map(boxed)
+                .unwrap_or_else(|_| widening_quine(&system.widen())),
+            Self::Bits128(system) => widening_quine(system),
+            Self::Unbounded(system) => quine(system)
+                .map(boxed)
+                .expect("arbitrary-precision registers never overflow"),
+        };
+        Ok(a.unwrap_or(Box::new("no solution")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::{load, registers};
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_programs_on_load() {
+        let message = |text: &str| load::<u64>(text).unwrap_err().to_string();
+
+        assert_eq!(
+            message(&(registers("1") + "Program: 0,1,5")),
+            "5:14: opcode 5 has no operand"
+        );
+        assert_eq!(
+            message(&(registers("1") + "Program: 0,1,5,7")),
+            "5:16: combo operand 7 is reserved (out 7)"
+        );
+        assert_eq!(
+            message(&(registers("-4") + "Program: 0,1")),
+            "1: register A must not be negative, found -4"
+        );
+    }
+
+    #[test]
+    fn reports_runtime_errors_as_errors() {
+        let system = load(&(registers("5") + "Program: 3,0")).unwrap();
+        let err = Machine::new(system)
+            .part1()
+            .err()
+            .expect("the program never halts");
+        assert_eq!(err.to_string(), "no halt after 1000000 cycles");
+    }
+
+    #[test]
+    fn runs_with_registers_wide_enough_for_the_input() {
+        let program = "Program: 2,4,1,3,1,0,1,0,1,0,1,0,1,0,1,0,5,5,0,3,3,0";
+        let a = "27739475119111250105";
+        let machine = Machine::new(load(&(registers(a) + program)).unwrap());
+        assert!(matches!(machine, Machine::Bits128(_)));
+        assert_eq!(machine.part2().unwrap().to_string(), a);
+        assert_eq!(
+            machine.part1().unwrap().to_string(),
+            program.strip_prefix("Program: ").unwrap()
+        );
+
+        let huge = "1".repeat(45);
+        let machine = Machine::new(load(&(registers(&huge) + program)).unwrap());
+        assert!(matches!(machine, Machine::Unbounded(_)));
+    }
+}
@@ -0,0 +1,374 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    path::Path,
+};
+
+use crate::{open_input, AocError};
+
+use super::{
+    disasm::combo_name, parse_input, BigUint, Instruction, Operation, Overflow, System, Word,
+};
+
+/// A register value as a function of the registers at the start of the loop
+/// body
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    A,
+    B,
+    C,
+    Const(u64),
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Box<Expr>, Box<Expr>),
+    // the lowest 3 bits
+    Mod8(Box<Expr>),
+}
+
+fn shr(value: Expr, amount: Expr) -> Expr {
+    match (value, amount) {
+        (value, Expr::Const(0)) => value,
+        (Expr::Const(x), Expr::Const(y)) => Expr::Const(x.checked_shr(y as u32).unwrap_or(0)),
+        (Expr::Shr(value, inner), Expr::Const(y)) if matches!(*inner, Expr::Const(_)) => {
+            let Expr::Const(x) = *inner else {
+                unreachable!()
+            };
+            shr(*value, Expr::Const(x.saturating_add(y)))
+        }
+        (value, amount) => Expr::Shr(Box::new(value), Box::new(amount)),
+    }
+}
+
+fn xor(x: Expr, y: Expr) -> Expr {
+    match (x, y) {
+        (Expr::Const(x), Expr::Const(y)) => Expr::Const(x ^ y),
+        (x, Expr::Const(0)) | (Expr::Const(0), x) => x,
+        (x, y) if x == y => Expr::Const(0),
+        (Expr::Xor(x, inner), Expr::Const(y)) if matches!(*inner, Expr::Const(_)) => {
+            let Expr::Const(k) = *inner else {
+                unreachable!()
+            };
+            xor(*x, Expr::Const(k ^ y))
+        }
+        (x, y) => Expr::Xor(Box::new(x), Box::new(y)),
+    }
+}
+
+fn mod8(x: Expr) -> Expr {
+    match x {
+        Expr::Const(x) => Expr::Const(x & 0b111),
+        Expr::Mod8(x) => Expr::Mod8(x),
+        // the low bits of a xor only depend on the low bits of its operands
+        Expr::Xor(x, y) => xor(mod8(*x), mod8(*y)),
+        x => Expr::Mod8(Box::new(x)),
+    }
+}
+
+impl Expr {
+    fn eval<W: Word>(&self, a: &W, b: &W, c: &W) -> W {
+        match self {
+            Self::A => a.clone(),
+            Self::B => b.clone(),
+            Self::C => c.clone(),
+            Self::Const(x) => W::from_u64(*x),
+            // shifting every bit out leaves nothing, however far the shift
+            Self::Shr(x, y) => x.eval(a, b, c).shr(&y.eval(a, b, c)),
+            Self::Xor(x, y) => x.eval(a, b, c).xor(&y.eval(a, b, c)),
+            Self::Mod8(x) => W::from_u64(x.eval(a, b, c).low_bits()),
+        }
+    }
+
+    // whether the expression reads the initial value of B or C
+    fn reads_b_or_c(&self) -> bool {
+        match self {
+            Self::B | Self::C => true,
+            Self::A | Self::Const(_) => false,
+            Self::Shr(x, y) | Self::Xor(x, y) => x.reads_b_or_c() || y.reads_b_or_c(),
+            Self::Mod8(x) => x.reads_b_or_c(),
+        }
+    }
+
+    /// replaces A with `a`
+    fn substitute(&self, a: &Expr) -> Expr {
+        match self {
+            Self::A => a.clone(),
+            Self::B | Self::C | Self::Const(_) => self.clone(),
+            Self::Shr(x, y) => shr(x.substitute(a), y.substitute(a)),
+            Self::Xor(x, y) => xor(x.substitute(a), y.substitute(a)),
+            Self::Mod8(x) => mod8(x.substitute(a)),
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::A => write!(f, "A"),
+            Self::B => write!(f, "B"),
+            Self::C => write!(f, "C"),
+            Self::Const(x) => write!(f, "{}", x),
+            // a 3-bit slice of A, written high bit first
+            Self::Mod8(x) => match x.as_ref() {
+                Self::A => write!(f, "A[2:0]"),
+                Self::Shr(a, n) if **a == Self::A && matches!(**n, Self::Const(_)) => {
+                    let Self::Const(n) = **n else { unreachable!() };
+                    write!(f, "A[{}:{}]", n + 2, n)
+                }
+                x => write!(f, "({} % 8)", operand(x)),
+            },
+            Self::Shr(x, y) => write!(f, "{} >> {}", operand(x), operand(y)),
+            Self::Xor(x, y) => write!(f, "{} ^ {}", operand(x), operand(y)),
+        }
+    }
+}
+
+// an expression with parentheses if it is a binary operation
+fn operand(x: &Expr) -> String {
+    match x {
+        Expr::Shr(..) | Expr::Xor(..) => format!("({})", x),
+        _ => x.to_string(),
+    }
+}
+
+/// A program of the form `loop { ... adv n ... } while A != 0`
+struct Loop {
+    /// how far A is shifted right on each pass
+    shift: u64,
+    /// the values output on each pass, in terms of A at the start of the pass
+    outputs: Vec<Expr>,
+}
+
+fn combo(operand: i64, a: &Expr, b: &Expr, c: &Expr) -> Expr {
+    match operand {
+        4 => a.clone(),
+        5 => b.clone(),
+        6 => c.clone(),
+        n => Expr::Const(n as u64),
+    }
+}
+
+/// executes the loop body once with symbolic registers
+fn analyse(instructions: &[Instruction]) -> Result<Loop, String> {
+    let Some((last, body)) = instructions.split_last() else {
+        return Err("the program is empty".to_string());
+    };
+    if !matches!(last.operator, Operation::Jnz) || last.operand != 0 {
+        return Err("the program must end with `jnz 0`".to_string());
+    }
+
+    let (mut a, mut b, mut c) = (Expr::A, Expr::B, Expr::C);
+    let mut outputs = vec![];
+    let mut shift = None;
+    for instruction in body {
+        let operand = instruction.operand;
+        match instruction.operator {
+            Operation::Adv => match (operand, shift) {
+                (1..=3, None) => {
+                    shift = Some(operand as u64);
+                    a = shr(a, Expr::Const(operand as u64));
+                }
+                (_, None) => {
+                    return Err(format!(
+                        "A must be shifted by a literal from 1 to 3, not `adv {}`",
+                        combo_name(operand)
+                    ))
+                }
+                (_, Some(_)) => return Err("A must be shifted exactly once a pass".to_string()),
+            },
+            Operation::Bxl => b = xor(b, Expr::Const(operand as u64)),
+            Operation::Bst => b = mod8(combo(operand, &a, &b, &c)),
+            Operation::Jnz => return Err("the loop body must not jump".to_string()),
+            Operation::Bxc => b = xor(b, c.clone()),
+            Operation::Out => outputs.push(mod8(combo(operand, &a, &b, &c))),
+            Operation::Bdv => b = shr(a.clone(), combo(operand, &a, &b, &c)),
+            Operation::Cdv => c = shr(a.clone(), combo(operand, &a, &b, &c)),
+        }
+    }
+
+    let Some(shift) = shift else {
+        return Err("A is never shifted, so the loop never ends".to_string());
+    };
+    if outputs.is_empty() {
+        return Err("the loop body has no `out`".to_string());
+    }
+    if outputs.iter().any(|o| o.reads_b_or_c()) {
+        return Err("the output depends on B or C from the previous pass".to_string());
+    }
+    Ok(Loop { shift, outputs })
+}
+
+impl Loop {
+    /// the values output on pass `k`, in terms of the initial A
+    fn pass(&self, k: usize) -> Vec<Expr> {
+        let a = shr(Expr::A, Expr::Const(self.shift * k as u64));
+        self.outputs.iter().map(|o| o.substitute(&a)).collect()
+    }
+
+    /// every initial A for which the program outputs exactly `target`, in
+    /// increasing order, or `Overflow` if they do not fit in `W`
+    fn solve<W: Word>(&self, target: &[i64]) -> Result<Vec<W>, Overflow> {
+        let per_pass = self.outputs.len();
+        if target.is_empty() || !target.len().is_multiple_of(per_pass) {
+            return Ok(vec![]);
+        }
+        let passes = target.len() / per_pass;
+
+        // A at the start of the last pass is the top digit of the initial A;
+        // it must be non-zero for every earlier pass to loop again, and each
+        // earlier pass adds one more digit below it, which keeps the
+        // candidates in increasing order
+        let zero = W::from_u64(0);
+        let mut candidates = vec![zero.clone()];
+        for k in (0..passes).rev() {
+            let expected = &target[k * per_pass..(k + 1) * per_pass];
+            let mut next = vec![];
+            for a in &candidates {
+                for digit in 0..1 << self.shift {
+                    let a = a.push_bits(self.shift as u32, digit).ok_or(Overflow)?;
+                    if k + 1 == passes && passes > 1 && a.is_zero() {
+                        continue;
+                    }
+                    if self
+                        .outputs
+                        .iter()
+                        .zip(expected)
+                        .all(|(o, &e)| o.eval(&a, &zero, &zero).low_bits() as i64 == e)
+                    {
+                        next.push(a);
+                    }
+                }
+            }
+            candidates = next;
+        }
+        Ok(candidates)
+    }
+}
+
+// the solutions for A with registers `W` wide, keeping only those that the
+// interpreter agrees output `target`, written in decimal
+fn confirm<W: Word>(
+    program: &Loop,
+    system: &System<BigUint>,
+    target: &[i64],
+) -> Result<Vec<String>, Overflow> {
+    let system: System<W> = system.convert().ok_or(Overflow)?;
+    Ok(program
+        .solve(target)?
+        .into_iter()
+        .filter(|a: &W| {
+            let mut sys = system.with_reg_a(a.clone());
+            sys.run().is_ok() && sys.output == target
+        })
+        .map(|a| a.to_string())
+        .collect())
+}
+
+/// `symbolic <input> [target]`, where the target output defaults to the
+/// program itself
+pub(super) fn run(args: &[String]) -> Result<(), AocError> {
+    let (path, target) = match args {
+        [path] => (path, None),
+        [path, target] => (path, Some(target)),
+        _ => {
+            return Err(AocError::Usage(
+                "usage: aoc-2024 symbolic 17 <input> [target]".into(),
+            ))
+        }
+    };
+    let path = Path::new(path);
+    let system: System<BigUint> = parse_input(open_input(path)?).map_err(|e| e.in_file(path))?;
+    let target = match target {
+        Some(target) => target
+            .split(',')
+            .map(|v| v.trim().parse::<i64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| AocError::Usage(format!("invalid target output `{}`", target)))?,
+        None => system.program(),
+    };
+
+    let program = analyse(&system.instructions).map_err(AocError::Unsupported)?;
+
+    println!("each pass shifts A right by {}", program.shift);
+    for (k, output) in program.outputs.iter().enumerate() {
+        println!("out {}: {}", k, output);
+    }
+    println!();
+    let passes = target.len() / program.outputs.len();
+    for k in 0..passes {
+        for output in program.pass(k) {
+            println!("pass {}: {}", k, output);
+        }
+    }
+    println!();
+
+    let confirmed = confirm::<u64>(&program, &system, &target)
+        .or_else(|_| confirm::<u128>(&program, &system, &target))
+        .or_else(|_| confirm::<BigUint>(&program, &system, &target))
+        .expect("arbitrary-precision registers never overflow");
+    println!(
+        "{} values of A output {}",
+        confirmed.len(),
+        target
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    for a in confirmed {
+        println!("{}", a);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse_instructions;
+    use super::*;
+
+    fn analysed(program: &str) -> Result<Loop, String> {
+        analyse(&parse_instructions(program, 1).unwrap())
+    }
+
+    #[test]
+    fn analyses_the_loop_body() {
+        let program = analysed("0,3,5,4,3,0").unwrap();
+        assert_eq!(program.shift, 3);
+        assert_eq!(program.outputs.len(), 1);
+        assert_eq!(program.outputs[0].to_string(), "A[5:3]");
+        assert_eq!(program.pass(2)[0].to_string(), "A[11:9]");
+
+        assert_eq!(
+            analysed("0,3,5,4").err().unwrap(),
+            "the program must end with `jnz 0`"
+        );
+        assert_eq!(
+            analysed("0,3,0,3,5,4,3,0").err().unwrap(),
+            "A must be shifted exactly once a pass"
+        );
+        assert_eq!(
+            analysed("2,4,5,6,0,3,3,0").err().unwrap(),
+            "the output depends on B or C from the previous pass"
+        );
+    }
+
+    #[test]
+    fn solves_for_every_initial_a() {
+        let program = analysed("0,3,5,4,3,0").unwrap();
+        let solutions = program.solve::<u64>(&[0, 3, 5, 4, 3, 0]).unwrap();
+        assert_eq!(solutions, (117440..117448).collect::<Vec<u64>>());
+        assert_eq!(program.solve::<u64>(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn widens_when_a_does_not_fit() {
+        let text = "2,4,1,3,1,0,1,0,1,0,1,0,1,0,1,0,5,5,0,3,3,0";
+        let program = analysed(text).unwrap();
+        let target: Vec<i64> = text.split(',').map(|v| v.parse().unwrap()).collect();
+
+        assert_eq!(program.solve::<u64>(&target), Err(Overflow));
+        assert_eq!(
+            program.solve::<u128>(&target),
+            Ok(vec![27739475119111250105])
+        );
+        let big = program.solve::<BigUint>(&target).unwrap();
+        assert_eq!(big[0].to_string(), "27739475119111250105");
+    }
+}
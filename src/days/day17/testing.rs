@@ -0,0 +1,51 @@
+//! Helpers shared by the day 17 tests
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{open_input, AocError};
+
+use super::{parse_input, System, Word};
+
+/// An input file holding some text, removed again when dropped
+pub(super) struct TempInput {
+    path: PathBuf,
+}
+
+impl TempInput {
+    pub(super) fn new(text: &str) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = env::temp_dir().join(format!(
+            "aoc-2024-day17-{}-{}.txt",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&path, text).expect("the temporary directory is writable");
+        Self { path }
+    }
+
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempInput {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// parses an input file holding `text`
+pub(super) fn load<W: Word>(text: &str) -> Result<System<W>, AocError> {
+    let input = TempInput::new(text);
+    parse_input(open_input(input.path())?)
+}
+
+/// the register lines of an input, with B and C zero, and the blank line
+/// before the program
+pub(super) fn registers(a: &str) -> String {
+    format!("Register A: {}\nRegister B: 0\nRegister C: 0\n\n", a)
+}
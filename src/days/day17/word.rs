@@ -0,0 +1,234 @@
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    str::FromStr,
+};
+
+/// An unsigned register value. Registers only ever shift right, xor and mask,
+/// so a program never overflows a register; only building a larger A while
+/// searching for one can
+pub trait Word: Clone + Debug + PartialEq + Display + FromStr + 'static {
+    /// how wide the registers are, for error messages
+    const WIDTH: &'static str;
+
+    fn from_u64(value: u64) -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    /// the lowest 3 bits
+    fn low_bits(&self) -> u64;
+
+    /// shifts right, giving zero once every bit is shifted out, however large
+    /// the shift
+    fn shr(&self, amount: &Self) -> Self;
+
+    fn xor(&self, other: &Self) -> Self;
+
+    /// `(self << bits) | low`, or `None` if it would not fit
+    fn push_bits(&self, bits: u32, low: u64) -> Option<Self>;
+}
+
+macro_rules! primitive_word {
+    ($t:ty, $width:literal) => {
+        impl Word for $t {
+            const WIDTH: &'static str = $width;
+
+            fn from_u64(value: u64) -> Self {
+                value as $t
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+
+            fn low_bits(&self) -> u64 {
+                (*self & 0b111) as u64
+            }
+
+            fn shr(&self, amount: &Self) -> Self {
+                u32::try_from(*amount)
+                    .ok()
+                    .and_then(|amount| self.checked_shr(amount))
+                    .unwrap_or(0)
+            }
+
+            fn xor(&self, other: &Self) -> Self {
+                self ^ other
+            }
+
+            fn push_bits(&self, bits: u32, low: u64) -> Option<Self> {
+                if self.leading_zeros() < bits {
+                    None
+                } else {
+                    Some((self << bits) | low as $t)
+                }
+            }
+        }
+    };
+}
+
+primitive_word!(u64, "64-bit");
+primitive_word!(u128, "128-bit");
+
+/// An arbitrary-precision unsigned integer, as 64-bit limbs from least to
+/// most significant with no trailing zero limbs
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    fn normalize(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    // multiplies by `factor` and adds `addend`
+    fn mul_add(&mut self, factor: u64, addend: u64) {
+        let mut carry = addend as u128;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u64);
+        }
+    }
+
+    // divides by `divisor` in place and returns the remainder
+    fn div_rem(&mut self, divisor: u64) -> u64 {
+        let mut remainder = 0u128;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = (remainder << 64) | *limb as u128;
+            *limb = (value / divisor as u128) as u64;
+            remainder = value % divisor as u128;
+        }
+        *self = std::mem::take(self).normalize();
+        remainder as u64
+    }
+}
+
+impl Word for BigUint {
+    const WIDTH: &'static str = "arbitrary-precision";
+
+    fn from_u64(value: u64) -> Self {
+        Self { limbs: vec![value] }.normalize()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn low_bits(&self) -> u64 {
+        self.limbs.first().map_or(0, |l| l & 0b111)
+    }
+
+    fn shr(&self, amount: &Self) -> Self {
+        let amount = match amount.limbs[..] {
+            [] => return self.clone(),
+            [amount] if amount < self.bits() => amount,
+            _ => return Self::default(),
+        };
+        let (skip, shift) = ((amount / 64) as usize, (amount % 64) as u32);
+        let limbs = &self.limbs[skip..];
+        let shifted = (0..limbs.len())
+            .map(|k| {
+                let high = limbs.get(k + 1).copied().unwrap_or(0);
+                match shift {
+                    0 => limbs[k],
+                    _ => (limbs[k] >> shift) | (high << (64 - shift)),
+                }
+            })
+            .collect();
+        Self { limbs: shifted }.normalize()
+    }
+
+    fn xor(&self, other: &Self) -> Self {
+        let len = self.limbs.len().max(other.limbs.len());
+        let limbs = (0..len)
+            .map(|k| {
+                self.limbs.get(k).copied().unwrap_or(0) ^ other.limbs.get(k).copied().unwrap_or(0)
+            })
+            .collect();
+        Self { limbs }.normalize()
+    }
+
+    fn push_bits(&self, bits: u32, low: u64) -> Option<Self> {
+        let mut value = self.clone();
+        value.mul_add(1 << bits, low);
+        Some(value.normalize())
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // peel off 19 decimal digits at a time, the most that fit in a u64
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut value = self.clone();
+        let mut chunks = vec![];
+        while !value.is_zero() {
+            chunks.push(value.div_rem(CHUNK));
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((top, rest)) => {
+                write!(f, "{}", top)?;
+                for chunk in rest.iter().rev() {
+                    write!(f, "{:019}", chunk)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(());
+        }
+        let mut value = Self::default();
+        for c in s.chars() {
+            let digit = c.to_digit(10).ok_or(())?;
+            value.mul_add(10, digit as u64);
+        }
+        Ok(value.normalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn huge_shifts_clear_every_bit() {
+        assert_eq!(u64::MAX.shr(&64), 0);
+        assert_eq!(u64::MAX.shr(&u64::MAX), 0);
+        assert_eq!(u128::MAX.shr(&127), 1);
+
+        let big: BigUint = "340282366920938463463374607431768211456".parse().unwrap();
+        assert_eq!(big.shr(&BigUint::from_u64(128)), BigUint::from_u64(1));
+        assert!(big.shr(&BigUint::from_u64(129)).is_zero());
+        assert!(big.shr(&big).is_zero());
+    }
+
+    #[test]
+    fn detects_overflow() {
+        assert_eq!((u64::MAX >> 3).push_bits(3, 7), Some(u64::MAX));
+        assert_eq!((u64::MAX >> 2).push_bits(3, 0), None);
+
+        let big = BigUint::from_u64(u64::MAX).push_bits(3, 7).unwrap();
+        assert_eq!(big.to_string(), (u128::from(u64::MAX) << 3 | 7).to_string());
+    }
+}
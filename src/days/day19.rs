@@ -1,7 +1,9 @@
-use aoc_2024::read_input;
+use crate::{Answer, AocError, Day, InputFileBuffer, Solution};
 
 use std::collections::{HashMap, HashSet};
 
+pub const DAY: Day = Day::new::<Towels>(19);
+
 struct PossibleComposite {
     _memo: HashMap<String, bool>,
 }
@@ -16,7 +18,7 @@ impl From<&HashSet<String>> for PossibleComposite {
 
 impl PossibleComposite {
     pub fn is_possible(&mut self, comp: &String) -> bool {
-        if comp.len() == 0 {
+        if comp.is_empty() {
             return true;
         }
 
@@ -31,7 +33,7 @@ impl PossibleComposite {
 
         for i in 0..comp.len() - 1 {
             let (prefix, suffix) = (&comp[0..i + 1].to_string(), &comp[i + 1..].to_string());
-            if self.is_possible(&prefix) && self.is_possible(&suffix) {
+            if self.is_possible(prefix) && self.is_possible(suffix) {
                 self._memo.insert(comp.clone(), true);
                 return true;
             }
@@ -39,11 +41,11 @@ impl PossibleComposite {
 
         // could not find match
         self._memo.insert(comp.clone(), false);
-        return false;
+        false
     }
 }
 
-fn parse_options(options: String) -> HashSet<String> {
+fn parse_options(options: &str) -> HashSet<String> {
     HashSet::from_iter(options.split(", ").map(|s| s.to_string()))
 }
 
@@ -52,8 +54,8 @@ fn update_base_counts(
     memo: &mut HashMap<String, u64>,
     possibility: &mut PossibleComposite,
 ) {
-    let mut bases: Vec<String> = memo.iter().map(|(base, _)| base.clone()).collect();
-    bases.sort_by(|a, b| a.len().cmp(&b.len()));
+    let mut bases: Vec<String> = memo.keys().cloned().collect();
+    bases.sort_by_key(|a| a.len());
 
     // updating bases from smallest to largest in length
     for base in bases {
@@ -78,7 +80,7 @@ fn count_combinations(
         return 0;
     }
 
-    if comp.len() == 0 {
+    if comp.is_empty() {
         return 1;
     }
 
@@ -100,8 +102,8 @@ fn count_combinations(
 
 fn combinations(
     options: &HashSet<String>,
-    mut possibilities: &mut PossibleComposite,
-    composites: &Vec<String>,
+    possibilities: &mut PossibleComposite,
+    composites: &[String],
 ) -> Vec<(String, u64)> {
     let mut memo = HashMap::from_iter(options.iter().map(|base| (base.clone(), 1)));
 
@@ -112,30 +114,48 @@ fn combinations(
         .map(|comp| {
             (
                 comp.clone(),
-                count_combinations(comp, &options, &mut memo, &mut possibilities),
+                count_combinations(comp, options, &mut memo, possibilities),
             )
         })
         .collect()
 }
 
-fn main() {
-    let mut lines = read_input();
-    let options = parse_options(lines.next().unwrap().unwrap());
+pub struct Towels {
+    options: HashSet<String>,
+    composites: Vec<String>,
+}
 
-    lines.next();
+impl Solution for Towels {
+    fn parse(mut lines: InputFileBuffer) -> Result<Self, AocError> {
+        let options = parse_options(&lines.next_line("towel patterns")?);
 
-    let composites: Vec<String> = lines.map(|line| line.unwrap()).collect();
+        lines.skip_blank()?;
 
-    let mut possibilities = PossibleComposite::from(&options);
+        let composites: Vec<String> = lines.collect::<Result<_, _>>()?;
 
-    let possible_composites = composites
-        .iter()
-        .filter(|comp| possibilities.is_possible(comp))
-        .count();
+        Ok(Self {
+            options,
+            composites,
+        })
+    }
 
-    println!("pt1: {}", possible_composites);
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut possibilities = PossibleComposite::from(&self.options);
 
-    let combos = combinations(&options, &mut possibilities, &composites);
+        let possible_composites = self
+            .composites
+            .iter()
+            .filter(|comp| possibilities.is_possible(comp))
+            .count();
 
-    println!("pt2: {}", combos.iter().map(|(_, i)| i).sum::<u64>());
+        Ok(Box::new(possible_composites))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut possibilities = PossibleComposite::from(&self.options);
+
+        let combos = combinations(&self.options, &mut possibilities, &self.composites);
+
+        Ok(Box::new(combos.iter().map(|(_, i)| i).sum::<u64>()))
+    }
 }
@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use crate::{
+    parse_number, Answer, AocError, Day, Direction, FromChar, Grid, InputFileBuffer, Point,
+    Solution,
+};
+
+pub const DAY: Day = Day::new::<Race>(20);
+
+/// the fewest picoseconds a cheat must save to be counted
+const THRESHOLD: i32 = 100;
+
+#[derive(PartialEq)]
+enum Terrain {
+    Path,
+    Wall,
+    Start,
+    Finish,
+}
+
+impl FromChar for Terrain {
+    fn from_char(value: char) -> Option<Self> {
+        match value {
+            '.' => Some(Self::Path),
+            '#' => Some(Self::Wall),
+            'S' => Some(Self::Start),
+            'E' => Some(Self::Finish),
+            _ => None,
+        }
+    }
+}
+
+impl Terrain {
+    fn is_path(&self) -> bool {
+        *self != Self::Wall
+    }
+}
+
+struct Course {
+    start: Point,
+    finish: Point,
+    course: Grid<Terrain>,
+}
+
+impl TryFrom<&mut InputFileBuffer> for Course {
+    type Error = AocError;
+
+    fn try_from(lines: &mut InputFileBuffer) -> Result<Self, Self::Error> {
+        let course: Grid<Terrain> = Grid::parse(lines)?;
+
+        let end = lines.line() + 1;
+        Ok(Self {
+            start: course
+                .find(&Terrain::Start)
+                .ok_or(AocError::expected(end, "S"))?,
+            finish: course
+                .find(&Terrain::Finish)
+                .ok_or(AocError::expected(end, "E"))?,
+            course,
+        })
+    }
+}
+
+impl Course {
+    fn is_path(&self, p: Point) -> bool {
+        self.course.get(p).is_some_and(|t| t.is_path())
+    }
+}
+
+type Distances = Grid<i32>;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Cheat {
+    at: Point,
+    to: Point,
+    cost: i32,
+}
+
+fn build_memo(course: &Course) -> Distances {
+    let mut dist: Distances = Grid::new(course.course.width(), course.course.height(), 0);
+
+    let mut cost = 0;
+    let mut frontier = vec![course.finish];
+    let mut explored: HashSet<Point> = HashSet::new();
+
+    while let Some(p) = frontier.pop() {
+        dist[p] = cost;
+        cost += 1;
+        explored.insert(p);
+        for d in Direction::ALL {
+            let next = p + d;
+            if !explored.contains(&next) && course.is_path(next) {
+                frontier.push(next);
+            }
+        }
+    }
+
+    dist
+}
+
+fn cheat(course: &Course, dist: &Distances) -> Vec<Cheat> {
+    let mut cheats = vec![];
+
+    let mut frontier = vec![course.start];
+    let mut explored: HashSet<Point> = HashSet::new();
+    while let Some(p) = frontier.pop() {
+        explored.insert(p);
+        // add next step
+        for d in Direction::ALL {
+            let next = p + d;
+            if !explored.contains(&next) && course.is_path(next) {
+                frontier.push(next);
+            }
+
+            // check for cheats
+            let to = p + d.vector() * 2;
+            if course.is_path(to) && !explored.contains(&to) {
+                let cost = dist[to] - dist[p] + dist[course.start] + 2;
+                cheats.push(Cheat { at: p, to, cost });
+            }
+        }
+    }
+
+    cheats
+}
+
+fn cheat_savings(dist: &Distances, cheat_to: Point, cheat_from: Point) -> i32 {
+    let cheat_distance = cheat_to.manhattan(cheat_from);
+
+    dist[cheat_from] - dist[cheat_to] - cheat_distance
+}
+
+fn more_cheating(course: &Course, dist: &Distances, threshold: i32) -> Vec<Cheat> {
+    let mut cheats = vec![];
+
+    let mut frontier = vec![course.start];
+    let mut explored: HashSet<Point> = HashSet::new();
+    while let Some(p) = frontier.pop() {
+        explored.insert(p);
+        // add next step
+        for d in Direction::ALL {
+            let next = p + d;
+            if !explored.contains(&next) && course.is_path(next) {
+                frontier.push(next);
+            }
+        }
+
+        for cheat_i in p.i - 20..=p.i + 20 {
+            for cheat_j in p.j - 20..=p.j + 20 {
+                let to = Point::new(cheat_i, cheat_j);
+                if to.manhattan(p) > 20 {
+                    continue;
+                }
+                if course.is_path(to) {
+                    let savings = cheat_savings(dist, to, p);
+                    if savings >= threshold {
+                        cheats.push(Cheat {
+                            at: p,
+                            to,
+                            cost: savings,
+                        })
+                    }
+                }
+            }
+        }
+    }
+
+    cheats
+}
+
+// we first parse the input data and store the coordinates of the start
+// and end. Then, we backtrack from the end to store the regular remaining
+// cost to get from any spot on the track to the end.
+// Then, we navigate the course from the start and attempt to cheat at
+// every opportunity, tracking the cheat location, and the total cost after
+// the cheat.
+pub struct Race {
+    course: Course,
+    dist: Distances,
+    threshold: i32,
+}
+
+impl Solution for Race {
+    /// the course, optionally followed by a blank line and a `threshold: N`
+    /// line for samples too small to have cheats saving the puzzle's 100
+    /// picoseconds
+    fn parse(mut input: InputFileBuffer) -> Result<Self, AocError> {
+        let course = Course::try_from(&mut input)?;
+        let dist = build_memo(&course);
+        let mut threshold = THRESHOLD;
+        for l in input.numbered() {
+            let (i, l) = l?;
+            match l.split_once(':') {
+                Some(("threshold", value)) => threshold = parse_number(value, i)?,
+                None if l.trim().is_empty() => {}
+                _ => return Err(AocError::expected(i, "threshold: N")),
+            }
+        }
+        Ok(Self {
+            course,
+            dist,
+            threshold,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let mut cheats = cheat(&self.course, &self.dist);
+
+        cheats.sort_by_key(|c| c.cost);
+        let start_cost = self.dist[self.course.start];
+        let big_savings: Vec<&Cheat> = cheats
+            .iter()
+            .filter(|c| start_cost - c.cost >= self.threshold)
+            .collect();
+
+        Ok(Box::new(big_savings.len()))
+    }
+
+    fn part2(&self) -> Result<Answer, AocError> {
+        let more_cheats = more_cheating(&self.course, &self.dist, self.threshold);
+        Ok(Box::new(more_cheats.len()))
+    }
+}
@@ -1,11 +1,17 @@
 use std::collections::{HashMap, HashSet};
 
-use aoc_2024::read_input;
+use crate::{parse_number, Answer, AocError, Day, InputFileBuffer, Solution};
 use itertools::Itertools;
 
-fn parse_starts() -> Vec<u64> {
-    read_input()
-        .map(|s| s.unwrap().parse::<u64>().unwrap())
+pub const DAY: Day = Day::new::<Buyers>(22);
+
+/// four consecutive price changes
+type Changes = (i32, i32, i32, i32);
+
+fn parse_starts(input: InputFileBuffer) -> Result<Vec<u64>, AocError> {
+    input
+        .numbered()
+        .map(|l| l.and_then(|(i, s)| parse_number::<u64>(&s, i)))
         .collect()
 }
 
@@ -49,7 +55,7 @@ fn secret_sequence(secret: u64, iterations: usize, functions: Vec<fn(u64) -> u64
     sequence
 }
 
-fn sequence_prices(sequence: Vec<u64>) -> Vec<(u64, (i32, i32, i32, i32))> {
+fn sequence_prices(sequence: Vec<u64>) -> Vec<(u64, Changes)> {
     sequence
         .windows(2)
         .map(|vs| {
@@ -65,11 +71,8 @@ fn sequence_prices(sequence: Vec<u64>) -> Vec<(u64, (i32, i32, i32, i32))> {
         .collect()
 }
 
-fn store_value(
-    memo: &mut HashMap<(i32, i32, i32, i32), u64>,
-    sequence_prices: &Vec<(u64, (i32, i32, i32, i32))>,
-) {
-    let mut seen: HashSet<(i32, i32, i32, i32)> = HashSet::new();
+fn store_value(memo: &mut HashMap<Changes, u64>, sequence_prices: &[(u64, Changes)]) {
+    let mut seen: HashSet<Changes> = HashSet::new();
 
     for (price, sequence) in sequence_prices {
         if seen.contains(sequence) {
@@ -86,7 +89,7 @@ fn store_value(
     }
 }
 
-fn sequence_values(starts: &Vec<u64>) -> Vec<((i32, i32, i32, i32), u64)> {
+fn sequence_values(starts: &[u64]) -> Vec<(Changes, u64)> {
     let sequence_prices = starts
         .iter()
         .map(|start| sequence_prices(secret_sequence(*start, 2000, vec![first, second, third])))
@@ -100,19 +103,32 @@ fn sequence_values(starts: &Vec<u64>) -> Vec<((i32, i32, i32, i32), u64)> {
     memo.iter().map(|(seq, total)| (*seq, *total)).collect_vec()
 }
 
-fn main() {
-    let starts = parse_starts();
+pub struct Buyers {
+    starts: Vec<u64>,
+}
 
-    let pt1 = starts
-        .iter()
-        .map(|start| secret(*start, 2000, vec![first, second, third]))
-        .sum::<u64>();
+impl Solution for Buyers {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError> {
+        Ok(Self {
+            starts: parse_starts(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, AocError> {
+        let pt1 = self
+            .starts
+            .iter()
+            .map(|start| secret(*start, 2000, vec![first, second, third]))
+            .sum::<u64>();
 
-    println!("pt1: {:?}", pt1);
+        Ok(Box::new(pt1))
+    }
 
-    let mut values = sequence_values(&starts);
-    values.sort_by(|a, b| a.1.cmp(&b.1));
-    values.reverse();
+    fn part2(&self) -> Result<Answer, AocError> {
+        let mut values = sequence_values(&self.starts);
+        values.sort_by_key(|v| v.1);
+        values.reverse();
 
-    println!("pt2: {}", values[0].1);
+        Ok(Box::new(values[0].1))
+    }
 }
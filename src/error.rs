@@ -0,0 +1,151 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+    path::PathBuf,
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    /// a value on the given line could not be parsed
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    /// a line did not start with, or a section was missing, the expected text
+    MalformedSection {
+        line: usize,
+        expected: String,
+    },
+    InvalidCharacter {
+        line: usize,
+        column: usize,
+        character: char,
+    },
+    /// wraps an error with the input file it came from
+    InFile {
+        path: PathBuf,
+        source: Box<AocError>,
+    },
+    /// a command was given the wrong arguments
+    Usage(String),
+    /// the input is valid, but not something the command can handle
+    Unsupported(String),
+    /// the input is valid, but solving it failed
+    Runtime(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn parse_at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+
+    pub fn expected(line: usize, expected: impl Into<String>) -> Self {
+        Self::MalformedSection {
+            line,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn in_file(self, path: impl Into<PathBuf>) -> Self {
+        match self {
+            Self::InFile { .. } => self,
+            _ => Self::InFile {
+                path: path.into(),
+                source: Box::new(self),
+            },
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse {
+                line,
+                column: Some(column),
+                message,
+            } => write!(f, "{}:{}: {}", line, column, message),
+            Self::Parse {
+                line,
+                column: None,
+                message,
+            } => write!(f, "{}: {}", line, message),
+            Self::MalformedSection { line, expected } => {
+                write!(f, "{}: expected `{}`", line, expected)
+            }
+            Self::InvalidCharacter {
+                line,
+                column,
+                character,
+            } => write!(f, "{}:{}: invalid character `{}`", line, column, character),
+            Self::InFile { path, source } => match **source {
+                Self::Io(_) | Self::Runtime(_) | Self::Unsupported(_) => {
+                    write!(f, "{}: {}", path.display(), source)
+                }
+                _ => write!(f, "{}:{}", path.display(), source),
+            },
+            Self::Usage(usage) => write!(f, "{}", usage),
+            Self::Unsupported(message) => write!(f, "unsupported: {}", message),
+            Self::Runtime(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::InFile { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// parses a trimmed number, reporting the offending text and line on failure
+pub fn parse_number<T: FromStr>(s: &str, line: usize) -> Result<T, AocError> {
+    s.trim()
+        .parse::<T>()
+        .map_err(|_| AocError::parse(line, format!("invalid number `{}`", s.trim())))
+}
+
+/// converts every character of a line, reporting the column of the first
+/// unrecognized one
+pub fn parse_chars<T>(
+    s: &str,
+    line: usize,
+    convert: impl Fn(char) -> Option<T>,
+) -> Result<Vec<T>, AocError> {
+    s.chars()
+        .enumerate()
+        .map(|(j, c)| {
+            convert(c).ok_or(AocError::InvalidCharacter {
+                line,
+                column: j + 1,
+                character: c,
+            })
+        })
+        .collect()
+}
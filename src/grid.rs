@@ -0,0 +1,207 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::{AocError, Direction, Direction8, InputFileBuffer, Point};
+
+/// Conversion from a single map character
+pub trait FromChar: Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// A rectangular map stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: FromChar> Grid<T> {
+    /// reads rows until a blank line or the end of the input
+    pub fn parse(input: &mut InputFileBuffer) -> Result<Self, AocError> {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        while let Some(l) = input.next() {
+            let l = l?;
+            if l.is_empty() {
+                break;
+            }
+            let row = l
+                .chars()
+                .enumerate()
+                .map(|(j, c)| {
+                    T::from_char(c).ok_or(AocError::InvalidCharacter {
+                        line: input.line(),
+                        column: j + 1,
+                        character: c,
+                    })
+                })
+                .collect::<Result<Vec<T>, AocError>>()?;
+
+            if height == 0 {
+                width = row.len();
+            } else if row.len() != width {
+                return Err(AocError::parse(
+                    input.line(),
+                    format!("expected {} columns, found {}", width, row.len()),
+                ));
+            }
+            cells.extend(row);
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, p: Point) -> Option<usize> {
+        match p.index()? {
+            (i, j) if i < self.height && j < self.width => Some(i * self.width + j),
+            _ => None,
+        }
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        self.offset(p).is_some()
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.offset(p).map(|k| &self.cells[k])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.offset(p).map(|k| &mut self.cells[k])
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(j).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// every position, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| Point::new(i as i32, j as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// the in-bounds positions up, right, down and left of `p`
+    pub fn neighbors(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |d| p + *d)
+            .filter(|n| self.in_bounds(*n))
+    }
+
+    /// the in-bounds positions surrounding `p`, including diagonals
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::iter()
+            .map(move |d| p + d)
+            .filter(|n| self.in_bounds(*n))
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(p, _)| p)
+    }
+
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, t)| predicate(t))
+            .map(|(p, _)| p)
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|t| t == value)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", p))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for t in row {
+                write!(f, "{}", t)?;
+            }
+        }
+        Ok(())
+    }
+}
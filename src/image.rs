@@ -0,0 +1,277 @@
+//! Writers for greyscale images held in a `Grid<u8>`, where 0 is black and 255
+//! is white
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use crate::Grid;
+
+/// writes a binary PGM
+pub fn write_pgm(image: &Grid<u8>, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P5\n{} {}\n255\n", image.width(), image.height())?;
+    for row in image.rows() {
+        out.write_all(row)?;
+    }
+    Ok(())
+}
+
+/// the colour of a brightness on a black, red, yellow, white heat scale
+fn heat(value: u8) -> [u8; 3] {
+    let v = value as u32 * 3;
+    let channel = |start: u32| (v.saturating_sub(start).min(255)) as u8;
+    [channel(0), channel(255), channel(510)]
+}
+
+/// writes a binary PPM, colouring brightness as heat
+pub fn write_ppm(image: &Grid<u8>, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", image.width(), image.height())?;
+    for row in image.rows() {
+        let pixels: Vec<u8> = row.iter().flat_map(|&v| heat(v)).collect();
+        out.write_all(&pixels)?;
+    }
+    Ok(())
+}
+
+/// lays `frames`, which must all be the same size, out left to right in rows
+/// of `columns`, separated by `gap` pixels of `background`
+pub fn tile(frames: &[Grid<u8>], columns: usize, gap: usize, background: u8) -> Grid<u8> {
+    let Some(first) = frames.first() else {
+        return Grid::new(0, 0, background);
+    };
+    let (width, height) = (first.width(), first.height());
+    let columns = columns.clamp(1, frames.len());
+    let rows = frames.len().div_ceil(columns);
+
+    let mut image = Grid::new(
+        columns * (width + gap) - gap,
+        rows * (height + gap) - gap,
+        background,
+    );
+    for (k, frame) in frames.iter().enumerate() {
+        let (top, left) = (
+            (k / columns) * (height + gap),
+            (k % columns) * (width + gap),
+        );
+        for (p, &v) in frame.iter() {
+            let (i, j) = p.index().expect("grid points are not negative");
+            image[(top + i, left + j).into()] = v;
+        }
+    }
+    image
+}
+
+// the largest LZW code a GIF may use
+const MAX_CODE: u16 = 4095;
+
+/// packs variable-width codes least significant bit first
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// compresses 8-bit pixels with the variable-width LZW that GIF uses
+fn lzw(pixels: &[u8]) -> Vec<u8> {
+    const CLEAR: u16 = 256;
+    const END: u16 = 257;
+
+    let mut out = BitWriter {
+        bytes: vec![],
+        buffer: 0,
+        bits: 0,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = END + 1;
+    let mut width = 9;
+    out.write(CLEAR, width);
+
+    let Some((&first, rest)) = pixels.split_first() else {
+        out.write(END, width);
+        return out.finish();
+    };
+    let mut prefix = first as u16;
+    for &pixel in rest {
+        if let Some(&code) = table.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+        out.write(prefix, width);
+        if next > MAX_CODE {
+            // the table is full, so start a new one
+            out.write(CLEAR, width);
+            table.clear();
+            next = END + 1;
+            width = 9;
+        } else {
+            table.insert((prefix, pixel), next);
+            next += 1;
+            // the decoder adds each code one step later, so it widens when
+            // this side has assigned one code past the current width
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        }
+        prefix = pixel as u16;
+    }
+    out.write(prefix, width);
+    out.write(END, width);
+    out.finish()
+}
+
+/// An animated GIF of greyscale frames, written as they are added and looping
+/// forever
+pub struct GifWriter<W: Write> {
+    out: W,
+    width: usize,
+    height: usize,
+    /// how long each frame is shown, in hundredths of a second
+    delay: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(mut out: W, width: usize, height: usize, delay: u16) -> io::Result<Self> {
+        let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("a GIF cannot be {}x{}", width, height),
+            ));
+        };
+        out.write_all(b"GIF89a")?;
+        out.write_all(&w.to_le_bytes())?;
+        out.write_all(&h.to_le_bytes())?;
+        // a global colour table of 256 entries, then background and aspect
+        out.write_all(&[0xf7, 0, 0])?;
+        let palette: Vec<u8> = (0..=255).flat_map(|v| [v, v, v]).collect();
+        out.write_all(&palette)?;
+        // loop forever
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+        Ok(Self {
+            out,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn frame(&mut self, image: &Grid<u8>) -> io::Result<()> {
+        if (image.width(), image.height()) != (self.width, self.height) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "a {}x{} frame in a {}x{} GIF",
+                    image.width(),
+                    image.height(),
+                    self.width,
+                    self.height
+                ),
+            ));
+        }
+        let [delay_lo, delay_hi] = self.delay.to_le_bytes();
+        self.out
+            .write_all(&[0x21, 0xf9, 0x04, 0x00, delay_lo, delay_hi, 0x00, 0x00])?;
+
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&(self.width as u16).to_le_bytes())?;
+        self.out.write_all(&(self.height as u16).to_le_bytes())?;
+        // no local colour table, then the minimum code size
+        self.out.write_all(&[0x00, 8])?;
+
+        let pixels: Vec<u8> = image.rows().flatten().copied().collect();
+        for block in lzw(&pixels).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0x00])
+    }
+
+    /// writes the trailer and returns the output
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a GIF LZW decoder, to check the encoder against
+    fn unlzw(bytes: &[u8]) -> Vec<u8> {
+        let mut bits = bytes
+            .iter()
+            .flat_map(|b| (0..8).map(move |k| (b >> k) & 1 == 1));
+        let mut read = |width: u32| -> u16 {
+            (0..width).fold(0, |code, k| code | ((bits.next().unwrap() as u16) << k))
+        };
+
+        let mut pixels = vec![];
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut width = 9;
+        let mut previous: Option<Vec<u8>> = None;
+        loop {
+            let code = read(width);
+            match code {
+                256 => {
+                    table = (0..=255).map(|v| vec![v]).collect();
+                    table.extend([vec![], vec![]]);
+                    width = 9;
+                    previous = None;
+                    continue;
+                }
+                257 => return pixels,
+                _ => {}
+            }
+            let entry = match (table.get(code as usize), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(p)) => [p.clone(), vec![p[0]]].concat(),
+                (None, None) => panic!("undefined code {}", code),
+            };
+            if let Some(p) = previous {
+                if table.len() <= MAX_CODE as usize {
+                    table.push([p, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+            pixels.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trips() {
+        let mut seed = 17u32;
+        let noise: Vec<u8> = (0..60_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (seed >> 16) as u8 % 5
+            })
+            .collect();
+        for pixels in [vec![], vec![9], vec![0; 10_000], noise] {
+            assert_eq!(unlzw(&lzw(&pixels)), pixels);
+        }
+    }
+}
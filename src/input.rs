@@ -0,0 +1,84 @@
+use std::{
+    fs::File,
+    io::{prelude::*, BufReader, Lines},
+    path::{Path, PathBuf},
+};
+
+use crate::AocError;
+
+/// Line reader over a puzzle input that keeps track of the current line number
+pub struct InputFileBuffer {
+    path: PathBuf,
+    lines: Lines<BufReader<File>>,
+    line: usize,
+}
+
+impl InputFileBuffer {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| AocError::Io(e).in_file(path))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            lines: BufReader::new(file).lines(),
+            line: 0,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// the 1-based number of the line most recently returned
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// reads the next line, which must exist
+    pub fn next_line(&mut self, expected: &str) -> Result<String, AocError> {
+        match self.next() {
+            Some(l) => l,
+            None => Err(AocError::expected(self.line + 1, expected)),
+        }
+    }
+
+    /// reads the next line, which must start with `prefix`, and returns the
+    /// remainder
+    pub fn next_field(&mut self, prefix: &str) -> Result<String, AocError> {
+        let l = self.next_line(prefix)?;
+        match l.strip_prefix(prefix) {
+            Some(rest) => Ok(rest.to_string()),
+            None => Err(AocError::expected(self.line, prefix)),
+        }
+    }
+
+    /// consumes the blank line separating two sections
+    pub fn skip_blank(&mut self) -> Result<(), AocError> {
+        match self.next().transpose()? {
+            Some(l) if l.is_empty() => Ok(()),
+            Some(_) => Err(AocError::parse(self.line, "expected a blank line")),
+            None => Err(AocError::parse(self.line + 1, "expected a blank line")),
+        }
+    }
+
+    /// pairs each remaining line with its line number
+    pub fn numbered(self) -> impl Iterator<Item = Result<(usize, String), AocError>> {
+        let start = self.line;
+        self.enumerate()
+            .map(move |(i, l)| l.map(|l| (start + i + 1, l)))
+    }
+}
+
+impl Iterator for InputFileBuffer {
+    type Item = Result<String, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let l = self.lines.next()?;
+        self.line += 1;
+        Some(l.map_err(AocError::from))
+    }
+}
+
+pub fn open_input<P: AsRef<Path>>(path: P) -> Result<InputFileBuffer, AocError> {
+    InputFileBuffer::open(path)
+}
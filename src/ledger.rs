@@ -0,0 +1,121 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    path::Path,
+};
+
+use crate::{open_input, parse_number, AocError};
+
+/// The conventional location of the answer ledger
+pub const LEDGER_PATH: &str = "inputs/answers.txt";
+
+/// Accepted answers for each day's `input.txt`, one per line:
+///
+/// ```text
+/// # comments and blank lines are ignored
+/// day 12 pt1: 1424472
+/// day 12 pt2: 870202
+/// ```
+#[derive(Debug, Default)]
+pub struct Ledger {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+/// The outcome of checking one part's answer against the ledger
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// the ledger has an answer but the part produced none
+    Missing {
+        expected: String,
+    },
+    /// the part produced an answer the ledger does not have yet
+    New,
+}
+
+impl Ledger {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AocError> {
+        let input = open_input(path.as_ref())?;
+        let mut answers = BTreeMap::new();
+        for l in input.numbered() {
+            let (line, l) = l.map_err(|e| e.in_file(path.as_ref()))?;
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+            let (day, part, answer) = parse_entry(l, line).map_err(|e| e.in_file(path.as_ref()))?;
+            if answers.insert((day, part), answer).is_some() {
+                return Err(AocError::parse(
+                    line,
+                    format!("duplicate answer for day {} pt{}", day, part),
+                )
+                .in_file(path.as_ref()));
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    /// recorded answers for days not in `days`
+    pub fn unknown_days<'a>(&'a self, days: &'a [u8]) -> impl Iterator<Item = (u8, u8)> + 'a {
+        self.answers
+            .keys()
+            .copied()
+            .filter(move |(day, _)| !days.contains(day))
+    }
+
+    /// compares a produced answer, if any, with the recorded one
+    pub fn check(&self, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(day, part), answer) {
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Match,
+            (Some(expected), Some(_)) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+            (Some(expected), None) => Verdict::Missing {
+                expected: expected.to_string(),
+            },
+            (None, _) => Verdict::New,
+        }
+    }
+}
+
+fn parse_entry(l: &str, line: usize) -> Result<(u8, u8, String), AocError> {
+    let (key, answer) = l
+        .split_once(':')
+        .ok_or(AocError::expected(line, "day N ptN: answer"))?;
+    let Some(key) = key.strip_prefix("day ") else {
+        return Err(AocError::expected(line, "day"));
+    };
+    let Some((day, part)) = key.trim().split_once(' ') else {
+        return Err(AocError::expected(line, "pt1` or `pt2"));
+    };
+    let part = match part.trim() {
+        "pt1" => 1,
+        "pt2" => 2,
+        _ => return Err(AocError::expected(line, "pt1` or `pt2")),
+    };
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err(AocError::parse(line, "missing answer"));
+    }
+    Ok((parse_number(day, line)?, part, answer.to_string()))
+}
+
+/// A single ledger line
+pub struct Entry<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: &'a str,
+}
+
+impl Display for Entry<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} pt{}: {}", self.day, self.part, self.answer)
+    }
+}
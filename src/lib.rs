@@ -1,39 +1,21 @@
-use std::{
-    env,
-    fs::File,
-    io::prelude::*,
-    io::{BufReader, Lines},
-    path::Path,
-};
+mod bench;
+pub mod days;
+mod error;
+mod grid;
+mod image;
+mod input;
+mod ledger;
+mod point;
+mod solution;
 
-use num_traits::{AsPrimitive, PrimInt, Zero};
-
-pub type InputFileBuffer = Lines<BufReader<File>>;
-
-/// Assuming that the input file will be the first command line arg
-pub fn read_input() -> InputFileBuffer {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        panic!("not enough args!");
-    }
-
-    let path = Path::new(&args[1]);
-    if let Ok(file) = File::open(&path) {
-        return BufReader::new(file).lines();
-    } else {
-        panic!("failed to open file");
-    }
-}
-
-pub fn out_of_bounds<T, U>(collection: &Vec<Vec<T>>, i: U, j: U) -> bool
-where
-    U: AsPrimitive<usize> + PrimInt,
-{
-    i < Zero::zero()
-        || j < Zero::zero()
-        || i.as_() >= collection.len()
-        || j.as_() >= collection[0].len()
-}
+pub use bench::{bench, print_csv, print_table, BenchConfig, DayTimings, Stats};
+pub use error::{parse_chars, parse_number, AocError};
+pub use grid::{FromChar, Grid};
+pub use image::{tile, write_pgm, write_ppm, GifWriter};
+pub use input::{open_input, InputFileBuffer};
+pub use ledger::{Entry, Ledger, Verdict, LEDGER_PATH};
+pub use point::Point;
+pub use solution::{Answer, Day, Mode, Solution, UNSOLVED};
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 pub enum Direction {
@@ -44,12 +26,15 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn vector(&self) -> (i32, i32) {
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// the (i, j) offset of one step in this direction
+    pub fn vector(&self) -> Point {
         match *self {
-            Self::Up => (-1, 0),
-            Self::Down => (1, 0),
-            Self::Right => (0, 1),
-            Self::Left => (0, -1),
+            Self::Up => Point::new(-1, 0),
+            Self::Down => Point::new(1, 0),
+            Self::Right => Point::new(0, 1),
+            Self::Left => Point::new(0, -1),
         }
     }
 
@@ -81,9 +66,9 @@ impl Direction {
     }
 }
 
-impl From<(i32, i32)> for Direction {
-    fn from(vector: (i32, i32)) -> Self {
-        match vector {
+impl From<Point> for Direction {
+    fn from(vector: Point) -> Self {
+        match (vector.i, vector.j) {
             (0, 1) => Self::Right,
             (0, -1) => Self::Left,
             (-1, 0) => Self::Up,
@@ -93,26 +78,117 @@ impl From<(i32, i32)> for Direction {
     }
 }
 
-impl From<&(i32, i32)> for Direction {
-    fn from(vector: &(i32, i32)) -> Self {
-        match *vector {
-            (0, 1) => Self::Right,
-            (0, -1) => Self::Left,
-            (-1, 0) => Self::Up,
-            (1, 0) => Self::Down,
-            _ => panic!("invalid input!"),
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Self::Up),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            '>' => Ok(Self::Right),
+            _ => Err(c),
         }
     }
 }
 
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
-        match c {
-            '^' => Self::Up,
-            'v' => Self::Down,
-            '<' => Self::Left,
-            '>' => Self::Right,
-            _ => panic!("unrecognized character!"),
+/// A compass direction including the diagonals, in clockwise order from `Up`
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub const DIAGONALS: [Direction8; 4] =
+        [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.into_iter()
+    }
+
+    /// the (i, j) offset of one step in this direction
+    pub fn vector(&self) -> Point {
+        match *self {
+            Self::Up => Point::new(-1, 0),
+            Self::UpRight => Point::new(-1, 1),
+            Self::Right => Point::new(0, 1),
+            Self::DownRight => Point::new(1, 1),
+            Self::Down => Point::new(1, 0),
+            Self::DownLeft => Point::new(1, -1),
+            Self::Left => Point::new(0, -1),
+            Self::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        Direction::try_from(*self).is_err()
+    }
+
+    // rotates by `eighths` of a full turn, clockwise
+    fn rotate(&self, eighths: usize) -> Self {
+        Self::ALL[(*self as usize + eighths) % 8]
+    }
+
+    pub fn rotate_clockwise_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn rotate_counter_clockwise_45(&self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn rotate_clockwise_90(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn rotate_counter_clockwise_90(&self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::Up,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+            Direction::Right => Self::Right,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::Up => Ok(Self::Up),
+            Direction8::Down => Ok(Self::Down),
+            Direction8::Left => Ok(Self::Left),
+            Direction8::Right => Ok(Self::Right),
+            _ => Err(direction),
         }
     }
 }
@@ -1,3 +1,260 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+use aoc_2024::{
+    bench, days, open_input, print_csv, print_table, Answer, AocError, BenchConfig, Day, Entry,
+    Ledger, Verdict, LEDGER_PATH, UNSOLVED,
+};
+
+const USAGE: &str = "usage: aoc-2024 run <day|all> [input]
+       aoc-2024 verify [ledger]
+       aoc-2024 bench <day|all> [input] [--warmup N] [--runs N] [--csv]";
+
+/// the usage message, including the modes each day offers
+fn usage() -> String {
+    let mut usage = USAGE.to_string();
+    for day in days::DAYS {
+        for mode in day.modes {
+            usage.push_str(&format!(
+                "\n       aoc-2024 {} {} {}",
+                mode.name, day.number, mode.usage
+            ));
+        }
+    }
+    usage
+}
+
+fn run(day: &Day, path: &Path) -> Result<(), AocError> {
+    let solution = day.parse(open_input(path)?)?;
+
+    println!("pt1: {}", solution.part1().map_err(|e| e.in_file(path))?);
+    println!("pt2: {}", solution.part2().map_err(|e| e.in_file(path))?);
+    Ok(())
+}
+
+fn run_all() -> bool {
+    let mut ok = true;
+    for day in days::DAYS {
+        println!("day {}", day.number);
+        if let Err(e) = run(day, &day.input_path()) {
+            eprintln!("{}", e);
+            ok = false;
+        }
+        println!();
+    }
+    ok
+}
+
+/// the answer to compare with the ledger, reporting a part that failed
+fn produced(answer: Result<Answer, AocError>, path: &Path) -> Option<String> {
+    match answer {
+        Ok(answer) => Some(answer.to_string()).filter(|a| a != UNSOLVED),
+        Err(e) => {
+            eprintln!("{}", e.in_file(path));
+            None
+        }
+    }
+}
+
+/// re-runs every day on its input and compares the answers with the ledger
+fn verify(path: &Path) -> Result<bool, AocError> {
+    let ledger = Ledger::load(path)?;
+    let (mut matched, mut failed, mut new) = (0, 0, 0);
+
+    for day in days::DAYS {
+        let answers = match open_input(day.input_path()).and_then(|input| day.parse(input)) {
+            Ok(solution) => {
+                let path = day.input_path();
+                [
+                    produced(solution.part1(), &path),
+                    produced(solution.part2(), &path),
+                ]
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                [None, None]
+            }
+        };
+
+        for (part, answer) in (1..=2).zip(answers) {
+            match ledger.check(day.number, part, answer.as_deref()) {
+                Verdict::Match => matched += 1,
+                Verdict::Mismatch { expected } => {
+                    println!(
+                        "mismatch: day {} pt{}: got {}, expected {}",
+                        day.number,
+                        part,
+                        answer.unwrap_or_default(),
+                        expected
+                    );
+                    failed += 1;
+                }
+                Verdict::Missing { expected } => {
+                    println!(
+                        "missing:  day {} pt{}: no answer, expected {}",
+                        day.number, part, expected
+                    );
+                    failed += 1;
+                }
+                Verdict::New => {
+                    if let Some(answer) = answer {
+                        let entry = Entry {
+                            day: day.number,
+                            part,
+                            answer: &answer,
+                        };
+                        println!("new:      {}", entry);
+                        new += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let registered: Vec<u8> = days::DAYS.iter().map(|d| d.number).collect();
+    for (day, part) in ledger.unknown_days(&registered) {
+        println!("missing:  day {} pt{}: no solution registered", day, part);
+        failed += 1;
+    }
+
+    println!(
+        "{} matched, {} mismatched or missing, {} new",
+        matched, failed, new
+    );
+    Ok(failed == 0)
+}
+
+/// the days and inputs named by `<day|all> [input]`
+fn targets(day: &str, input: Option<&String>) -> Result<Vec<(&'static Day, PathBuf)>, String> {
+    if day == "all" {
+        return match input {
+            None => Ok(days::DAYS.iter().map(|d| (d, d.input_path())).collect()),
+            Some(_) => Err(usage()),
+        };
+    }
+    let Some(found) = day.parse::<u8>().ok().and_then(days::find) else {
+        return Err(format!("no solution registered for day {}", day));
+    };
+    let path = match input {
+        Some(path) => PathBuf::from(path),
+        None => found.input_path(),
+    };
+    Ok(vec![(found, path)])
+}
+
+fn bench_command(args: &[String]) -> Result<bool, String> {
+    let mut config = BenchConfig::default();
+    let mut csv = false;
+    let mut positional = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--csv" => csv = true,
+            "--warmup" | "--runs" => {
+                let n = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .ok_or(format!("{} expects a number", arg))?;
+                if arg == "--warmup" {
+                    config.warmup = n;
+                } else {
+                    config.runs = n;
+                }
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let targets = match positional[..] {
+        [day] => targets(day, None)?,
+        [day, input] => targets(day, Some(input))?,
+        _ => return Err(usage()),
+    };
+
+    let mut ok = true;
+    let mut timings = vec![];
+    for (day, path) in targets {
+        match bench(day, &path, config) {
+            Ok(t) => timings.push(t),
+            Err(e) => {
+                eprintln!("{}", e);
+                ok = false;
+            }
+        }
+    }
+
+    if csv {
+        print_csv(&timings);
+    } else {
+        print_table(&timings);
+    }
+    Ok(ok)
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.iter().map(|s| s.as_str()).collect::<Vec<&str>>()[..] {
+        ["run", "all"] => {
+            if !run_all() {
+                exit(1);
+            }
+        }
+        ["run", day] | ["run", day, _] => {
+            let Some(day) = day.parse::<u8>().ok().and_then(days::find) else {
+                eprintln!("no solution registered for day {}", day);
+                exit(1);
+            };
+            let path = match args.get(2) {
+                Some(path) => Path::new(path).to_path_buf(),
+                None => day.input_path(),
+            };
+            if let Err(e) = run(day, &path) {
+                eprintln!("{}", e);
+                exit(1);
+            }
+        }
+        ["verify"] | ["verify", _] => {
+            let path = args.get(1).map_or(LEDGER_PATH, |p| p.as_str());
+            match verify(Path::new(path)) {
+                Ok(true) => {}
+                Ok(false) => exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    exit(1);
+                }
+            }
+        }
+        ["bench", ..] => match bench_command(&args[1..]) {
+            Ok(true) => {}
+            Ok(false) => exit(1),
+            Err(e) => {
+                eprintln!("{}", e);
+                exit(1);
+            }
+        },
+        [mode, day, ..] => {
+            let Some(mode) = day
+                .parse::<u8>()
+                .ok()
+                .and_then(days::find)
+                .and_then(|d| d.mode(mode))
+            else {
+                eprintln!("{}", usage());
+                exit(1);
+            };
+            if let Err(e) = (mode.run)(&args[2..]) {
+                eprintln!("{}", e);
+                exit(1);
+            }
+        }
+        _ => {
+            eprintln!("{}", usage());
+            exit(1);
+        }
+    }
 }
@@ -0,0 +1,131 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::{Direction, Direction8};
+
+/// A position on a grid, as (row, column), or the offset between two positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub i: i32,
+    pub j: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(i: i32, j: i32) -> Self {
+        Self { i, j }
+    }
+
+    /// the number of orthogonal steps between two points
+    pub fn manhattan(&self, other: Point) -> i32 {
+        (self.i - other.i).abs() + (self.j - other.j).abs()
+    }
+
+    /// the number of steps between two points when diagonal steps are allowed
+    pub fn chebyshev(&self, other: Point) -> i32 {
+        (self.i - other.i).abs().max((self.j - other.j).abs())
+    }
+
+    /// (row, column) indices into a `Vec<Vec<T>>`, or `None` if either is negative
+    pub fn index(&self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.i).ok()?, usize::try_from(self.j).ok()?))
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((i, j): (i32, i32)) -> Self {
+        Self::new(i, j)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((i, j): (usize, usize)) -> Self {
+        Self::new(i as i32, j as i32)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Self::new(self.i + rhs.i, self.j + rhs.j)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<Direction8> for Point {
+    fn add_assign(&mut self, rhs: Direction8) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Self::new(self.i - rhs.i, self.j - rhs.j)
+    }
+}
+
+impl Sub<Direction> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Direction) -> Self::Output {
+        self - rhs.vector()
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<Direction> for Point {
+    fn sub_assign(&mut self, rhs: Direction) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self::new(self.i * rhs, self.j * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.i, -self.j)
+    }
+}
@@ -0,0 +1,74 @@
+use std::{fmt::Display, path::PathBuf};
+
+use crate::{AocError, InputFileBuffer};
+
+/// A displayable puzzle answer
+pub type Answer = Box<dyn Display>;
+
+/// The answer given by parts that have not been solved yet
+pub const UNSOLVED: &str = "unsolved";
+
+/// A single day's puzzle. The parsed input is the solution itself, and each
+/// part computes its answer from it.
+pub trait Solution {
+    fn parse(input: InputFileBuffer) -> Result<Self, AocError>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Result<Answer, AocError>;
+
+    fn part2(&self) -> Result<Answer, AocError>;
+}
+
+fn parse<S: Solution + 'static>(input: InputFileBuffer) -> Result<Box<dyn Solution>, AocError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// An extra command offered by a day, run as `aoc-2024 <name> <day> [args]`
+pub struct Mode {
+    pub name: &'static str,
+    /// the arguments the mode takes, for the usage message
+    pub usage: &'static str,
+    pub run: fn(&[String]) -> Result<(), AocError>,
+}
+
+/// Registry entry tying a day number to its solution
+pub struct Day {
+    pub number: u8,
+    parse: fn(InputFileBuffer) -> Result<Box<dyn Solution>, AocError>,
+    pub modes: &'static [Mode],
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(number: u8) -> Self {
+        Self {
+            number,
+            parse: parse::<S>,
+            modes: &[],
+        }
+    }
+
+    pub const fn with_modes(self, modes: &'static [Mode]) -> Self {
+        Self { modes, ..self }
+    }
+
+    pub fn mode(&self, name: &str) -> Option<&Mode> {
+        self.modes.iter().find(|m| m.name == name)
+    }
+
+    /// parses the input, attributing any error to the input file
+    pub fn parse(&self, input: InputFileBuffer) -> Result<Box<dyn Solution>, AocError> {
+        let path = input.path().to_path_buf();
+        (self.parse)(input).map_err(|e| e.in_file(path))
+    }
+
+    /// the directory holding the day's puzzle input and sample files
+    pub fn input_dir(&self) -> PathBuf {
+        PathBuf::from(format!("inputs/day-{}", self.number))
+    }
+
+    /// the conventional location of the day's puzzle input
+    pub fn input_path(&self) -> PathBuf {
+        self.input_dir().join("input.txt")
+    }
+}
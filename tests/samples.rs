@@ -0,0 +1,102 @@
+//! Runs every registered day against its `inputs/day-N/test*.txt` samples.
+//!
+//! A sample is checked when a file with the same stem and an `.expected`
+//! extension sits next to it, holding the answers in the same form the
+//! binary prints them:
+//!
+//! ```text
+//! pt1: 1930
+//! pt2: 1206
+//! ```
+//!
+//! Parts missing from the `.expected` file are not checked.
+
+use std::{fs, path::Path};
+
+use aoc_2024::{days, open_input, Answer, AocError, Day};
+
+// the answer as printed, or the error in its place
+fn shown(answer: Result<Answer, AocError>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+fn check(day: &Day, sample: &Path, expected_path: &Path) -> Vec<String> {
+    let expected = match fs::read_to_string(expected_path) {
+        Ok(expected) => expected,
+        Err(e) => return vec![format!("{}: {}", expected_path.display(), e)],
+    };
+    let solution = match open_input(sample).and_then(|input| day.parse(input)) {
+        Ok(solution) => solution,
+        Err(e) => return vec![e.to_string()],
+    };
+
+    let mut failures = vec![];
+    for (n, line) in expected.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (part, answer, actual) = match line.split_once(':') {
+            Some(("pt1", answer)) => ("pt1", answer.trim(), shown(solution.part1())),
+            Some(("pt2", answer)) => ("pt2", answer.trim(), shown(solution.part2())),
+            _ => {
+                failures.push(format!(
+                    "{}:{}: expected `pt1:` or `pt2:`",
+                    expected_path.display(),
+                    n + 1
+                ));
+                continue;
+            }
+        };
+        if actual != answer {
+            failures.push(format!(
+                "{}: {} was {}, expected {}",
+                sample.display(),
+                part,
+                actual,
+                answer
+            ));
+        }
+    }
+    failures
+}
+
+#[test]
+fn samples() {
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for day in days::DAYS {
+        let Ok(entries) = fs::read_dir(day.input_dir()) else {
+            continue;
+        };
+        let mut samples: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                let name = p.file_name().unwrap().to_string_lossy();
+                name.starts_with("test") && name.ends_with(".txt")
+            })
+            .collect();
+        samples.sort();
+
+        for sample in samples {
+            let expected = sample.with_extension("expected");
+            if !expected.exists() {
+                continue;
+            }
+            failures.extend(check(day, &sample, &expected));
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "no samples with expected answers found");
+    assert!(
+        failures.is_empty(),
+        "{} samples checked, failures:\n{}",
+        checked,
+        failures.join("\n")
+    );
+}